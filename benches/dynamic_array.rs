@@ -0,0 +1,77 @@
+//! Compares the throughput of repeated `add` calls on a `DynamicArray`,
+//! which grows its buffer by half its capacity as OpenJDK 8 `ArrayList`
+//! does, against `push` calls on a `Vec`, which doubles its capacity.
+//!
+//! Run with `cargo bench --bench dynamic_array`
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use dsa_in_rust::dynamic_array::DynamicArray;
+
+const SIZES: [usize; 3] = [1_000, 10_000, 100_000];
+
+fn add_from_empty(c: &mut Criterion) {
+    let mut group = c.benchmark_group("add_from_empty");
+
+    for size in SIZES.iter() {
+        group.bench_with_input(BenchmarkId::new("DynamicArray", size), size, |b, &size| {
+            b.iter(|| {
+                let mut array = DynamicArray::new();
+
+                for i in 0..size {
+                    array.add(black_box(i));
+                }
+
+                array
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("Vec", size), size, |b, &size| {
+            b.iter(|| {
+                let mut vec = Vec::new();
+
+                for i in 0..size {
+                    vec.push(black_box(i));
+                }
+
+                vec
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn add_with_capacity(c: &mut Criterion) {
+    let mut group = c.benchmark_group("add_with_capacity");
+
+    for size in SIZES.iter() {
+        group.bench_with_input(BenchmarkId::new("DynamicArray", size), size, |b, &size| {
+            b.iter(|| {
+                let mut array = DynamicArray::with_capacity(size);
+
+                for i in 0..size {
+                    array.add(black_box(i));
+                }
+
+                assert_eq!(array.capacity(), size);
+                array
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("Vec", size), size, |b, &size| {
+            b.iter(|| {
+                let mut vec = Vec::with_capacity(size);
+
+                for i in 0..size {
+                    vec.push(black_box(i));
+                }
+
+                vec
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, add_from_empty, add_with_capacity);
+criterion_main!(benches);
@@ -2,9 +2,15 @@
 //!
 //! A Binary Search Tree is a rooted binary tree whose internal nodes each
 //! store a key greater than all the keys in the node's left subtree and less
-//! than those in its right subtree
+//! than those in its right subtree.
+//!
+//! Values are ordered by a `Comparator`, which defaults to the `Ord`
+//! implementation of the values
 use std::mem::replace;
 
+use crate::comparator::{Comparator, NaturalOrder};
+use crate::traits::Collection;
+
 /// A sub-tree from this Binary Search Tree
 type Tree<T: std::cmp::Ord + std::fmt::Debug + Clone> = Option<Box<Node<T>>>;
 
@@ -13,10 +19,13 @@ type Tree<T: std::cmp::Ord + std::fmt::Debug + Clone> = Option<Box<Node<T>>>;
 /// `Tree` where the `left` side `Tree` contains all `Node`s lower
 /// than  the current's `Node` value. And the right side of the tree
 /// contains all `Node`s with a greather value than this `Node`'s
-/// value
+/// value.
+///
+/// The `size` is the count of `Node`s on the sub-tree rooted at this `Node`
 #[derive(Debug)]
 pub struct Node<T: std::cmp::Ord + std::fmt::Debug + Clone> {
     value: T,
+    size: u64,
     left: Tree<T>,
     right: Tree<T>,
 }
@@ -25,6 +34,7 @@ impl<T: std::cmp::Ord + std::fmt::Debug + Clone> Node<T> {
     pub fn new(value: T) -> Self {
         Node {
             value,
+            size: 1,
             left: None,
             right: None,
         }
@@ -41,18 +51,29 @@ impl<T: std::cmp::Ord + std::fmt::Debug + Clone> Node<T> {
 
 /// A Binary Search Tree is a rooted binary tree whose internal nodes each
 /// store a key greater than all the keys in the node's left subtree and less
-/// than those in its right subtree
+/// than those in its right subtree, where the order is given by the
+/// `comparator`
 #[derive(Debug)]
-pub struct BinarySearchTree<T: std::cmp::Ord + std::fmt::Debug + Clone> {
+pub struct BinarySearchTree<T: std::cmp::Ord + std::fmt::Debug + Clone, C = NaturalOrder> {
     root: Tree<T>,
     length: u64,
+    comparator: C,
 }
 
 impl<T: std::cmp::Ord + std::fmt::Debug + Clone> BinarySearchTree<T> {
     pub fn new() -> Self {
+        BinarySearchTree::with_comparator(NaturalOrder)
+    }
+}
+
+impl<T: std::cmp::Ord + std::fmt::Debug + Clone, C: Comparator<T>> BinarySearchTree<T, C> {
+    /// Creates a new `BinarySearchTree` which orders its values using the
+    /// provided `comparator`
+    pub fn with_comparator(comparator: C) -> Self {
         BinarySearchTree {
             root: None,
             length: 0,
+            comparator,
         }
     }
 
@@ -74,7 +95,9 @@ impl<T: std::cmp::Ord + std::fmt::Debug + Clone> BinarySearchTree<T> {
     /// The value is added when the `value` of the `Tree<T>` equals to `None`
     fn add_recursive(&mut self, node: Tree<T>, value: T) -> Tree<T> {
         if let Some(mut node) = node {
-            if value <= node.value {
+            node.size += 1;
+
+            if self.comparator.compare(&value, &node.value) != std::cmp::Ordering::Greater {
                 node.left = self.add_recursive(node.left, value);
                 return Some(node);
             }
@@ -86,6 +109,86 @@ impl<T: std::cmp::Ord + std::fmt::Debug + Clone> BinarySearchTree<T> {
         Some(Node::boxed(value))
     }
 
+    /// Removes a `value` from the `BinarySearchTree<T>`.
+    ///
+    /// A `Node` with both childrens is replaced by the lowest `Node` of its
+    /// `right` side `Tree<T>`. Returns `true` if the `value` were found and
+    /// removed
+    pub fn remove(&mut self, value: &T) -> bool {
+        let root = self.root.take();
+        let (root, removed) = self.remove_recursive(root, value);
+
+        self.root = root;
+
+        if removed {
+            self.length -= 1;
+        }
+
+        removed
+    }
+
+    /// Walks the tree recursively looking for the `Node<T>` holding `value`,
+    /// decreasing the `size` of every `Node<T>` on the path when it's
+    /// removed
+    fn remove_recursive(&self, node: Tree<T>, value: &T) -> (Tree<T>, bool) {
+        let mut node = match node {
+            Some(node) => node,
+            None => return (None, false),
+        };
+
+        let removed = match self.comparator.compare(value, &node.value) {
+            std::cmp::Ordering::Less => {
+                let (left, removed) = self.remove_recursive(node.left.take(), value);
+
+                node.left = left;
+                removed
+            }
+            std::cmp::Ordering::Greater => {
+                let (right, removed) = self.remove_recursive(node.right.take(), value);
+
+                node.right = right;
+                removed
+            }
+            std::cmp::Ordering::Equal => {
+                return match (node.left.take(), node.right.take()) {
+                    (None, right) => (right, true),
+                    (left, None) => (left, true),
+                    (left, Some(right)) => {
+                        let (right, mut successor) = Self::take_leftmost(right);
+
+                        successor.size = node.size - 1;
+                        successor.left = left;
+                        successor.right = right;
+
+                        (Some(successor), true)
+                    }
+                };
+            }
+        };
+
+        if removed {
+            node.size -= 1;
+        }
+
+        (Some(node), removed)
+    }
+
+    /// Detaches the lowest `Node<T>` from the `Tree<T>` rooted at `node`,
+    /// returning the remaining `Tree<T>` and the detached `Node<T>`
+    fn take_leftmost(mut node: Box<Node<T>>) -> (Tree<T>, Box<Node<T>>) {
+        match node.left.take() {
+            Some(left) => {
+                let (left, leftmost) = Self::take_leftmost(left);
+
+                node.left = left;
+                node.size -= 1;
+
+                (Some(node), leftmost)
+            }
+            None => (node.right.take(), node),
+        }
+    }
+
     /// Searches the tree for the provided value
     pub fn find(&self, value: T) -> Option<T> {
         self.find_recursive(&self.root, value)
@@ -94,9 +197,8 @@ impl<T: std::cmp::Ord + std::fmt::Debug + Clone> BinarySearchTree<T> {
     /// Walks the tree recursively looking for the node with the exact
     /// value as `value`
     fn find_recursive(&self, node: &Tree<T>, value: T) -> Option<T> {
-        println!("Node: {:#?} - Value: {:#?}", node, value);
         if let Some(node) = node {
-            return match node.value.cmp(&value) {
+            return match self.comparator.compare(&node.value, &value) {
                 std::cmp::Ordering::Less => self.find_recursive(&node.right, value),
                 std::cmp::Ordering::Equal => Some(node.value.clone()),
                 std::cmp::Ordering::Greater => self.find_recursive(&node.left, value),
@@ -106,6 +208,229 @@ impl<T: std::cmp::Ord + std::fmt::Debug + Clone> BinarySearchTree<T> {
         None
     }
 
+    /// Retrieves the greatest value of the tree lower than or equal to
+    /// `value`.
+    ///
+    /// The tree is descended from the root keeping the last value found
+    /// lower than or equal to `value` as the candidate
+    pub fn floor(&self, value: &T) -> Option<T> {
+        let mut candidate = None;
+        let mut current = &self.root;
+
+        while let Some(node) = current {
+            current = match self.comparator.compare(&node.value, value) {
+                std::cmp::Ordering::Less => {
+                    candidate = Some(&node.value);
+                    &node.right
+                }
+                std::cmp::Ordering::Equal => return Some(node.value.clone()),
+                std::cmp::Ordering::Greater => &node.left,
+            };
+        }
+
+        candidate.cloned()
+    }
+
+    /// Retrieves the lowest value of the tree greater than or equal to
+    /// `value`.
+    ///
+    /// The tree is descended from the root keeping the last value found
+    /// greater than or equal to `value` as the candidate
+    pub fn ceil(&self, value: &T) -> Option<T> {
+        let mut candidate = None;
+        let mut current = &self.root;
+
+        while let Some(node) = current {
+            current = match self.comparator.compare(&node.value, value) {
+                std::cmp::Ordering::Less => &node.right,
+                std::cmp::Ordering::Equal => return Some(node.value.clone()),
+                std::cmp::Ordering::Greater => {
+                    candidate = Some(&node.value);
+                    &node.left
+                }
+            };
+        }
+
+        candidate.cloned()
+    }
+
+    /// Retrieves the count of values of the tree lower than `value`.
+    ///
+    /// The tree is descended from the root, every time the path goes to the
+    /// `right` side the `Node` and its `left` side `Tree<T>` are counted
+    pub fn rank(&self, value: &T) -> u64 {
+        let mut rank = 0;
+        let mut current = &self.root;
+
+        while let Some(node) = current {
+            current = match self.comparator.compare(&node.value, value) {
+                std::cmp::Ordering::Less => {
+                    rank += size_of(&node.left) + 1;
+                    &node.right
+                }
+                _ => &node.left,
+            };
+        }
+
+        rank
+    }
+
+    /// Retrieves the `k`-th lowest value of the tree, where `k` starts from
+    /// 0.
+    ///
+    /// The tree is descended from the root using the `size` of the `left`
+    /// side `Tree<T>` of each `Node` to pick the side holding the value
+    pub fn select(&self, k: u64) -> Option<T> {
+        let mut k = k;
+        let mut current = &self.root;
+
+        while let Some(node) = current {
+            let left_size = size_of(&node.left);
+
+            current = match k.cmp(&left_size) {
+                std::cmp::Ordering::Less => &node.left,
+                std::cmp::Ordering::Equal => return Some(node.value.clone()),
+                std::cmp::Ordering::Greater => {
+                    k -= left_size + 1;
+                    &node.right
+                }
+            };
+        }
+
+        None
+    }
+
+    /// Retrieves every value of the tree equal to `value` in order.
+    ///
+    /// Equal values are added to the `left` side of a `Node`, but removing
+    /// or merging values may move them to the `right` side, so once a
+    /// `Node` with an equal value is found the search continues on both
+    /// sides
+    pub fn find_all(&self, value: &T) -> Vec<T> {
+        let mut values = Vec::new();
+
+        self.find_all_recursive(&self.root, value, &mut values);
+        values
+    }
+
+    fn find_all_recursive(&self, node: &Tree<T>, value: &T, values: &mut Vec<T>) {
+        if let Some(node) = node {
+            match self.comparator.compare(&node.value, value) {
+                std::cmp::Ordering::Less => self.find_all_recursive(&node.right, value, values),
+                std::cmp::Ordering::Equal => {
+                    self.find_all_recursive(&node.left, value, values);
+                    values.push(node.value.clone());
+                    self.find_all_recursive(&node.right, value, values);
+                }
+                std::cmp::Ordering::Greater => self.find_all_recursive(&node.left, value, values),
+            }
+        }
+    }
+
+    /// Moves every value of `other` into this tree.
+    ///
+    /// The values of `other` are taken in order and added middle first, so
+    /// the merged values are spread evenly instead of growing a single
+    /// branch
+    pub fn merge(&mut self, other: BinarySearchTree<T, C>) {
+        let mut values = Vec::with_capacity(other.length as usize);
+
+        Self::move_in_order(other.root, &mut values);
+        self.add_balanced(&values);
+    }
+
+    /// Removes every value for which `pred` returns `false`.
+    ///
+    /// The tree is rebuilt from the retained values, which are added middle
+    /// first to keep it balanced
+    pub fn retain<F: Fn(&T) -> bool>(&mut self, pred: F) {
+        let mut values = Vec::with_capacity(self.length as usize);
+
+        Self::move_in_order(self.root.take(), &mut values);
+        values.retain(|value| pred(value));
+        self.length = 0;
+        self.add_balanced(&values);
+    }
+
+    fn move_in_order(node: Tree<T>, values: &mut Vec<T>) {
+        if let Some(node) = node {
+            let node = *node;
+
+            Self::move_in_order(node.left, values);
+            values.push(node.value);
+            Self::move_in_order(node.right, values);
+        }
+    }
+
+    /// Adds the values of a sorted slice picking the middle value first and
+    /// then the middle value of each half, which keeps the `Tree<T>`
+    /// balanced
+    pub(crate) fn add_balanced(&mut self, sorted: &[T]) {
+        if sorted.is_empty() {
+            return;
+        }
+
+        let middle = sorted.len() / 2;
+
+        self.add(sorted[middle].clone());
+        self.add_balanced(&sorted[..middle]);
+        self.add_balanced(&sorted[middle + 1..]);
+    }
+
+    /// Retrieves the count of `Node`s on the longest path from the root to
+    /// a leaf, an empty tree has a height of 0
+    pub fn height(&self) -> usize {
+        Self::height_recursive(&self.root)
+    }
+
+    fn height_recursive(node: &Tree<T>) -> usize {
+        match node {
+            Some(node) => {
+                1 + std::cmp::max(
+                    Self::height_recursive(&node.left),
+                    Self::height_recursive(&node.right),
+                )
+            }
+            None => 0,
+        }
+    }
+
+    /// Retrieves the count of `Node`s on each level of the tree, where the
+    /// index 0 is the level of the root.
+    ///
+    /// The tree is walked level by level (breadth first)
+    pub fn level_widths(&self) -> Vec<u64> {
+        let mut widths = Vec::new();
+        let mut level: Vec<&Node<T>> = self.root.iter().map(|node| node.as_ref()).collect();
+
+        while !level.is_empty() {
+            widths.push(level.len() as u64);
+            level = level
+                .iter()
+                .flat_map(|node| node.left.iter().chain(node.right.iter()))
+                .map(|node| node.as_ref())
+                .collect();
+        }
+
+        widths
+    }
+
+    /// Collects references to the values of the tree in order
+    pub fn in_order(&self) -> Vec<&T> {
+        let mut values = Vec::with_capacity(self.length as usize);
+
+        Self::collect_in_order(&self.root, &mut values);
+        values
+    }
+
+    fn collect_in_order<'a>(node: &'a Tree<T>, values: &mut Vec<&'a T>) {
+        if let Some(node) = node {
+            Self::collect_in_order(&node.left, values);
+            values.push(&node.value);
+            Self::collect_in_order(&node.right, values);
+        }
+    }
+
     pub fn walk(&self, func: impl Fn(&T) -> ()) {
         self.walk_in_order(&self.root, &func);
     }
@@ -119,6 +444,61 @@ impl<T: std::cmp::Ord + std::fmt::Debug + Clone> BinarySearchTree<T> {
     }
 }
 
+impl<T: std::cmp::Ord + std::fmt::Debug + Clone, C: Comparator<T> + Default> Default
+    for BinarySearchTree<T, C>
+{
+    fn default() -> Self {
+        BinarySearchTree::with_comparator(C::default())
+    }
+}
+
+impl<T: std::cmp::Ord + std::fmt::Debug + Clone, C: Comparator<T>> Collection<T>
+    for BinarySearchTree<T, C>
+{
+    fn len(&self) -> usize {
+        self.length as usize
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        self.find(value.clone()).is_some()
+    }
+}
+
+/// Retrieves the count of `Node`s on the provided `Tree<T>`
+fn size_of<T: std::cmp::Ord + std::fmt::Debug + Clone>(tree: &Tree<T>) -> u64 {
+    tree.as_ref().map_or(0, |node| node.size)
+}
+
+/// Serializes the tree as the sequence of its values in order
+#[cfg(feature = "serde")]
+impl<T, C> serde::Serialize for BinarySearchTree<T, C>
+where
+    T: std::cmp::Ord + std::fmt::Debug + Clone + serde::Serialize,
+    C: Comparator<T>,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.in_order())
+    }
+}
+
+/// Deserializes a tree from a sequence of values, which are sorted and
+/// added middle first so the resulting tree is balanced
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for BinarySearchTree<T>
+where
+    T: std::cmp::Ord + std::fmt::Debug + Clone + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut values = Vec::<T>::deserialize(deserializer)?;
+        let mut bst = BinarySearchTree::new();
+
+        values.sort();
+        bst.add_balanced(&values);
+
+        Ok(bst)
+    }
+}
+
 #[allow(unused_imports)]
 mod tests {
     use std::cell::RefCell;
@@ -126,6 +506,21 @@ mod tests {
 
     use super::*;
 
+    /// Checks that the `size` of every `Node` on `tree` equals the count of
+    /// `Node`s on its sub-tree, returning the count of `Node`s on `tree`
+    #[allow(dead_code)]
+    fn checked_size<T: std::cmp::Ord + std::fmt::Debug + Clone>(tree: &Tree<T>) -> u64 {
+        match tree {
+            Some(node) => {
+                let size = 1 + checked_size(&node.left) + checked_size(&node.right);
+
+                assert_eq!(node.size, size);
+                size
+            }
+            None => 0,
+        }
+    }
+
     #[test]
     fn creates_an_empty_bst() {
         let bst = BinarySearchTree::<u64>::new();
@@ -207,4 +602,326 @@ mod tests {
 
         assert_eq!(touched_items, expect);
     }
+
+    #[test]
+    fn retrieves_the_height_of_the_bst() {
+        let mut bst = BinarySearchTree::<u64>::new();
+
+        assert_eq!(bst.height(), 0);
+
+        bst.add(5);
+
+        assert_eq!(bst.height(), 1);
+
+        bst.add(3);
+        bst.add(7);
+
+        assert_eq!(bst.height(), 2);
+
+        bst.add(8);
+        bst.add(9);
+
+        assert_eq!(bst.height(), 4);
+    }
+
+    #[test]
+    fn orders_values_with_a_comparator() {
+        use crate::comparator::Reverse;
+
+        let mut bst = BinarySearchTree::with_comparator(Reverse(NaturalOrder));
+
+        bst.add(5);
+        bst.add(3);
+        bst.add(7);
+        bst.add(1);
+
+        assert_eq!(bst.in_order(), vec![&7, &5, &3, &1]);
+        assert_eq!(bst.find(3), Some(3));
+        assert_eq!(bst.find(4), None);
+    }
+
+    #[test]
+    fn orders_values_by_a_derived_key() {
+        let mut bst = BinarySearchTree::with_comparator(|a: &&str, b: &&str| a.len().cmp(&b.len()));
+
+        bst.add("ccc");
+        bst.add("a");
+        bst.add("bb");
+
+        assert_eq!(bst.in_order(), vec![&"a", &"bb", &"ccc"]);
+        assert_eq!(bst.find("zz"), Some("bb"));
+    }
+
+    #[test]
+    fn merges_two_bsts() {
+        let mut bst = BinarySearchTree::<u64>::new();
+        let mut other = BinarySearchTree::<u64>::new();
+
+        bst.add(5);
+        bst.add(2);
+        bst.add(8);
+
+        for value in 1..=7 {
+            other.add(value * 2);
+        }
+
+        bst.merge(other);
+
+        assert_eq!(bst.length, 10);
+        assert_eq!(
+            bst.in_order(),
+            vec![&2, &2, &4, &5, &6, &8, &8, &10, &12, &14]
+        );
+        assert_eq!(bst.height(), 4);
+    }
+
+    #[test]
+    fn retains_values_matching_a_predicate() {
+        let mut bst = BinarySearchTree::<u64>::new();
+
+        for value in 1..=10 {
+            bst.add(value);
+        }
+
+        bst.retain(|value| value % 2 == 0);
+
+        assert_eq!(bst.length, 5);
+        assert_eq!(bst.in_order(), vec![&2, &4, &6, &8, &10]);
+        assert_eq!(bst.height(), 3);
+
+        bst.retain(|_| false);
+
+        assert!(bst.root.is_none());
+        assert_eq!(bst.length, 0);
+    }
+
+    #[test]
+    fn finds_every_equal_value_in_bst() {
+        let mut bst = BinarySearchTree::<u64>::new();
+
+        bst.add(5);
+        bst.add(3);
+        bst.add(5);
+        bst.add(8);
+        bst.add(4);
+        bst.add(5);
+
+        assert_eq!(bst.find_all(&5), vec![5, 5, 5]);
+        assert_eq!(bst.find_all(&3), vec![3]);
+        assert_eq!(bst.find_all(&7), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn finds_every_value_with_an_equal_key() {
+        let mut bst =
+            BinarySearchTree::with_comparator(|a: &(u32, char), b: &(u32, char)| a.0.cmp(&b.0));
+
+        bst.add((2, 'a'));
+        bst.add((1, 'b'));
+        bst.add((2, 'c'));
+        bst.add((2, 'd'));
+
+        let mut found = bst.find_all(&(2, ' '));
+
+        found.sort();
+
+        assert_eq!(found, vec![(2, 'a'), (2, 'c'), (2, 'd')]);
+    }
+
+    #[test]
+    fn finds_every_equal_value_after_removing_a_node_with_two_children() {
+        let mut bst = BinarySearchTree::<u64>::new();
+
+        for value in [10, 5, 20, 15, 15] {
+            bst.add(value);
+        }
+
+        assert!(bst.remove(&10));
+        assert_eq!(bst.in_order(), vec![&5, &15, &15, &20]);
+        assert_eq!(bst.find_all(&15), vec![15, 15]);
+
+        let mut merged = BinarySearchTree::<u64>::new();
+
+        merged.add(15);
+        bst.merge(merged);
+
+        assert_eq!(bst.find_all(&15), vec![15, 15, 15]);
+    }
+
+    #[test]
+    fn retrieves_the_width_of_each_level() {
+        let mut bst = BinarySearchTree::<u64>::new();
+
+        assert_eq!(bst.level_widths(), Vec::<u64>::new());
+
+        for value in [8, 3, 10, 1, 6, 14, 4, 7, 13].iter() {
+            bst.add(*value);
+        }
+
+        assert_eq!(bst.level_widths(), vec![1, 2, 3, 3]);
+    }
+
+    #[test]
+    fn retrieves_the_floor_and_ceil_values() {
+        let mut bst = BinarySearchTree::<u64>::new();
+
+        for value in [8, 3, 10, 1, 6, 14, 4, 7, 13].iter() {
+            bst.add(*value);
+        }
+
+        assert_eq!(bst.floor(&6), Some(6));
+        assert_eq!(bst.ceil(&6), Some(6));
+        assert_eq!(bst.floor(&5), Some(4));
+        assert_eq!(bst.ceil(&5), Some(6));
+        assert_eq!(bst.floor(&12), Some(10));
+        assert_eq!(bst.ceil(&12), Some(13));
+        assert_eq!(bst.floor(&0), None);
+        assert_eq!(bst.ceil(&0), Some(1));
+        assert_eq!(bst.floor(&20), Some(14));
+        assert_eq!(bst.ceil(&20), None);
+    }
+
+    #[test]
+    fn retrieves_the_rank_of_values() {
+        let mut bst = BinarySearchTree::<u64>::new();
+
+        for value in [8, 3, 10, 1, 6, 14, 4, 7, 13].iter() {
+            bst.add(*value);
+        }
+
+        assert_eq!(bst.rank(&1), 0);
+        assert_eq!(bst.rank(&6), 3);
+        assert_eq!(bst.rank(&9), 6);
+        assert_eq!(bst.rank(&0), 0);
+        assert_eq!(bst.rank(&20), 9);
+    }
+
+    #[test]
+    fn selects_the_kth_lowest_value() {
+        let mut bst = BinarySearchTree::<u64>::new();
+
+        for value in [8, 3, 10, 1, 6, 14, 4, 7, 13].iter() {
+            bst.add(*value);
+        }
+
+        assert_eq!(bst.select(0), Some(1));
+        assert_eq!(bst.select(3), Some(6));
+        assert_eq!(bst.select(8), Some(14));
+        assert_eq!(bst.select(9), None);
+    }
+
+    #[test]
+    fn removes_values_from_a_bst() {
+        let mut bst = BinarySearchTree::<u64>::new();
+
+        for value in [8, 3, 10, 1, 6, 14, 4, 7, 13].iter() {
+            bst.add(*value);
+        }
+
+        assert!(bst.remove(&3));
+        assert!(bst.remove(&14));
+        assert!(bst.remove(&1));
+        assert!(!bst.remove(&2));
+        assert!(bst.remove(&8));
+
+        assert_eq!(bst.length, 5);
+        assert_eq!(bst.in_order(), vec![&4, &6, &7, &10, &13]);
+        assert_eq!(checked_size(&bst.root), 5);
+    }
+
+    #[test]
+    fn keeps_subtree_sizes_across_adds_and_removals() {
+        let mut bst = BinarySearchTree::<u64>::new();
+
+        for value in [5, 3, 8, 3, 9, 1, 7].iter() {
+            bst.add(*value);
+            checked_size(&bst.root);
+        }
+
+        assert_eq!(bst.root.as_ref().unwrap().size, 7);
+
+        for value in [3, 5, 10, 1].iter() {
+            bst.remove(value);
+            checked_size(&bst.root);
+        }
+
+        assert_eq!(bst.root.as_ref().unwrap().size, 4);
+        assert_eq!(bst.in_order(), vec![&3, &7, &8, &9]);
+    }
+
+    #[test]
+    fn matches_rank_and_select_against_a_sorted_reference() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(11);
+        let mut bst = BinarySearchTree::<u64>::new();
+        let mut reference = Vec::new();
+
+        for _ in 0..200 {
+            let value = rng.gen_range(0..100);
+
+            bst.add(value);
+            reference.push(value);
+        }
+
+        for _ in 0..80 {
+            let value = rng.gen_range(0..100);
+
+            let removed = match reference.iter().position(|item| *item == value) {
+                Some(index) => {
+                    reference.remove(index);
+                    true
+                }
+                None => false,
+            };
+
+            assert_eq!(bst.remove(&value), removed);
+        }
+
+        reference.sort_unstable();
+        checked_size(&bst.root);
+
+        assert_eq!(bst.length, reference.len() as u64);
+
+        for value in 0..=100 {
+            let rank = reference.iter().filter(|item| **item < value).count() as u64;
+
+            assert_eq!(bst.rank(&value), rank);
+        }
+
+        for (k, value) in reference.iter().enumerate() {
+            assert_eq!(bst.select(k as u64), Some(*value));
+        }
+
+        assert_eq!(bst.select(reference.len() as u64), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_and_deserializes_a_bst() {
+        let mut bst = BinarySearchTree::<u64>::new();
+
+        for value in [8, 3, 10, 1, 6, 14, 4, 7, 13].iter() {
+            bst.add(*value);
+        }
+
+        let json = serde_json::to_string(&bst).unwrap();
+
+        assert_eq!(json, "[1,3,4,6,7,8,10,13,14]");
+
+        let restored: BinarySearchTree<u64> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.length, bst.length);
+        assert_eq!(restored.in_order(), bst.in_order());
+        assert_eq!(restored.root.as_ref().unwrap().value, 7);
+    }
+
+    #[test]
+    fn creates_an_empty_bst_by_default() {
+        let bst: BinarySearchTree<u64> = Default::default();
+
+        assert!(bst.root.is_none());
+        assert_eq!(bst.length, 0);
+    }
 }
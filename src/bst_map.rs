@@ -0,0 +1,176 @@
+//! Binary Search Tree Map
+//!
+//! A Binary Search Tree where each `Node` holds a value of type `V` under a
+//! key of type `K`, `Node`s are ordered by their keys and a key is present
+//! at most once in the map
+use std::cmp::Ordering;
+
+/// A sub-tree from this Binary Search Tree Map
+type Tree<K, V> = Option<Box<Node<K, V>>>;
+
+/// A node containing a `key`, its `value` and their childrens, the `left`
+/// side `Tree` holds lower keys and the `right` side `Tree` holds greater
+/// keys
+#[derive(Debug)]
+pub struct Node<K: Ord, V> {
+    key: K,
+    value: V,
+    left: Tree<K, V>,
+    right: Tree<K, V>,
+}
+
+impl<K: Ord, V> Node<K, V> {
+    pub fn boxed(key: K, value: V) -> Box<Self> {
+        Box::new(Node {
+            key,
+            value,
+            left: None,
+            right: None,
+        })
+    }
+}
+
+/// An ordered map of keys of type `K` to values of type `V` backed by a
+/// Binary Search Tree
+#[derive(Debug)]
+pub struct BstMap<K: Ord, V> {
+    root: Tree<K, V>,
+    length: usize,
+}
+
+impl<K: Ord, V> BstMap<K, V> {
+    pub fn new() -> Self {
+        BstMap {
+            root: None,
+            length: 0,
+        }
+    }
+
+    /// Retrieves the count of keys in the map
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the map holds no keys
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Inserts the `value` under the provided `key`, returning the value
+    /// previously held by the `key` if any
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let slot = Self::slot(&mut self.root, &key);
+
+        match slot {
+            Some(node) => Some(std::mem::replace(&mut node.value, value)),
+            None => {
+                *slot = Some(Node::boxed(key, value));
+                self.length += 1;
+                None
+            }
+        }
+    }
+
+    /// Retrieves a reference to the value held by the provided `key`
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut current = &self.root;
+
+        while let Some(node) = current {
+            current = match key.cmp(&node.key) {
+                Ordering::Less => &node.left,
+                Ordering::Equal => return Some(&node.value),
+                Ordering::Greater => &node.right,
+            };
+        }
+
+        None
+    }
+
+    /// Retrieves a mutable reference to the value held by the provided
+    /// `key`, if the `key` is not present the value returned by `f` is
+    /// inserted first.
+    ///
+    /// `f` is only executed when the `key` is not present
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce() -> V) -> &mut V {
+        let slot = Self::slot(&mut self.root, &key);
+
+        if slot.is_none() {
+            *slot = Some(Node::boxed(key, f()));
+            self.length += 1;
+        }
+
+        &mut slot.as_mut().expect("The slot holds a node").value
+    }
+
+    /// Walks the `tree` looking for the `Tree` holding the provided `key`,
+    /// which is an empty `Tree` if the `key` is not present
+    fn slot<'a>(tree: &'a mut Tree<K, V>, key: &K) -> &'a mut Tree<K, V> {
+        let mut current = tree;
+
+        while current.as_ref().is_some_and(|node| node.key != *key) {
+            let node = current.as_mut().expect("The slot holds a node");
+
+            current = if *key < node.key {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+        }
+
+        current
+    }
+}
+
+impl<K: Ord, V> Default for BstMap<K, V> {
+    fn default() -> Self {
+        BstMap::new()
+    }
+}
+
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn inserts_and_retrieves_values() {
+        let mut map = BstMap::<u32, &str>::new();
+
+        assert!(map.is_empty());
+        assert_eq!(map.insert(5, "five"), None);
+        assert_eq!(map.insert(3, "three"), None);
+        assert_eq!(map.insert(8, "eight"), None);
+        assert_eq!(map.insert(3, "tres"), Some("three"));
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get(&3), Some(&"tres"));
+        assert_eq!(map.get(&8), Some(&"eight"));
+        assert_eq!(map.get(&4), None);
+    }
+
+    #[test]
+    fn inserts_a_computed_value_for_a_missing_key() {
+        let mut map = BstMap::<&str, u32>::new();
+
+        map.insert("b", 2);
+
+        *map.get_or_insert_with("a", || 1) += 10;
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&"a"), Some(&11));
+    }
+
+    #[test]
+    fn retrieves_the_existing_value_without_computing_a_new_one() {
+        let mut map = BstMap::<&str, u32>::new();
+
+        map.insert("b", 2);
+        map.insert("a", 1);
+        map.insert("c", 3);
+
+        let value = map.get_or_insert_with("c", || panic!("The key is present"));
+
+        *value += 10;
+
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.get(&"c"), Some(&13));
+    }
+}
@@ -0,0 +1,66 @@
+//! Comparator
+//!
+//! Trees order their values using a `Comparator`, which by default is the
+//! `Ord` implementation of the values (`NaturalOrder`). Providing another
+//! `Comparator` allows ordering values in reverse or by a key derived from
+//! each value.
+use core::cmp::Ordering;
+
+/// Compares two values of type `T`
+pub trait Comparator<T> {
+    /// Retrieves the `Ordering` of `a` with respect to `b`
+    fn compare(&self, a: &T, b: &T) -> Ordering;
+}
+
+/// Orders values using their `Ord` implementation
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NaturalOrder;
+
+impl<T: Ord> Comparator<T> for NaturalOrder {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        a.cmp(b)
+    }
+}
+
+/// Orders values in the reverse order of the wrapped `Comparator`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Reverse<C>(pub C);
+
+impl<T, C: Comparator<T>> Comparator<T> for Reverse<C> {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self.0.compare(b, a)
+    }
+}
+
+/// Any closure comparing two values is a `Comparator`, which makes it easy
+/// to order values by a derived key
+impl<T, F: Fn(&T, &T) -> Ordering> Comparator<T> for F {
+    fn compare(&self, a: &T, b: &T) -> Ordering {
+        self(a, b)
+    }
+}
+
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn compares_values_in_natural_order() {
+        assert_eq!(NaturalOrder.compare(&1, &2), Ordering::Less);
+        assert_eq!(NaturalOrder.compare(&2, &2), Ordering::Equal);
+        assert_eq!(NaturalOrder.compare(&3, &2), Ordering::Greater);
+    }
+
+    #[test]
+    fn compares_values_in_reverse_order() {
+        assert_eq!(Reverse(NaturalOrder).compare(&1, &2), Ordering::Greater);
+        assert_eq!(Reverse(NaturalOrder).compare(&2, &2), Ordering::Equal);
+    }
+
+    #[test]
+    fn compares_values_with_a_closure() {
+        let by_length = |a: &&str, b: &&str| a.len().cmp(&b.len());
+
+        assert_eq!(by_length.compare(&"abc", &"z"), Ordering::Greater);
+    }
+}
@@ -0,0 +1,137 @@
+//! Deque
+//!
+//! A double-ended queue built on top of a `DoublyLinkedList`, values are
+//! pushed to and popped from both ends of the list in constant time
+use std::fmt::Debug;
+
+use crate::doubly_linked_list::DoublyLinkedList;
+use crate::traits::Collection;
+
+/// A double-ended collection of values of type `T`
+#[derive(Debug)]
+pub struct Deque<T: Clone + Debug> {
+    list: DoublyLinkedList<T>,
+}
+
+impl<T> Deque<T>
+where
+    T: Clone + Debug,
+{
+    /// Creates a new empty `Deque`
+    pub fn new() -> Self {
+        Deque {
+            list: DoublyLinkedList::new(),
+        }
+    }
+
+    /// Retrieves the count of values in the `Deque`
+    pub fn len(&self) -> usize {
+        self.list.len() as usize
+    }
+
+    /// Returns `true` if the `Deque` holds no values
+    pub fn is_empty(&self) -> bool {
+        self.list.len() == 0
+    }
+
+    /// Adds a value to the front of the `Deque`
+    pub fn push_front(&mut self, value: T) {
+        self.list.prepend(value);
+    }
+
+    /// Adds a value to the back of the `Deque`
+    pub fn push_back(&mut self, value: T) {
+        self.list.append(value);
+    }
+
+    /// Removes the value at the front of the `Deque` and returns it
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.pop()
+    }
+
+    /// Removes the value at the back of the `Deque` and returns it
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+
+    /// Retrieves a copy of the value at the front of the `Deque`
+    pub fn front(&self) -> Option<T> {
+        self.list.front()
+    }
+
+    /// Retrieves a copy of the value at the back of the `Deque`
+    pub fn back(&self) -> Option<T> {
+        self.list.back()
+    }
+}
+
+impl<T> Default for Deque<T>
+where
+    T: Clone + Debug,
+{
+    fn default() -> Self {
+        Deque::new()
+    }
+}
+
+impl<T> Collection<T> for Deque<T>
+where
+    T: Clone + Debug + PartialEq,
+{
+    fn len(&self) -> usize {
+        self.list.len() as usize
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        self.list.contains(value)
+    }
+}
+
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn creates_an_empty_deque() {
+        let deque = Deque::<u32>::new();
+
+        assert!(deque.is_empty());
+        assert_eq!(deque.front(), None);
+        assert_eq!(deque.back(), None);
+    }
+
+    #[test]
+    fn pushes_and_pops_on_both_ends() {
+        let mut deque = Deque::<u32>::new();
+
+        deque.push_back(2);
+        deque.push_front(1);
+        deque.push_back(3);
+
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.front(), Some(1));
+        assert_eq!(deque.back(), Some(3));
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.front(), Some(2));
+        assert_eq!(deque.back(), Some(2));
+        assert_eq!(deque.pop_back(), Some(2));
+        assert!(deque.is_empty());
+        assert_eq!(deque.pop_front(), None);
+        assert_eq!(deque.pop_back(), None);
+
+        deque.push_front(4);
+
+        assert_eq!(deque.front(), Some(4));
+        assert_eq!(deque.back(), Some(4));
+        assert_eq!(deque.pop_front(), Some(4));
+        assert!(deque.is_empty());
+    }
+
+    #[test]
+    fn creates_an_empty_deque_by_default() {
+        let deque: Deque<u32> = Default::default();
+
+        assert!(deque.is_empty());
+    }
+}
@@ -3,8 +3,11 @@
 //! A Data Structure where each `Node` holds a pointer to the next `Node`
 use std::cell::RefCell;
 use std::fmt::Debug;
+use std::marker::PhantomData;
 use std::rc::{Rc, Weak};
 
+use crate::traits::Collection;
+
 /// A `Link` to the next `Node`
 pub type Link<T> = Option<Rc<RefCell<Node<T>>>>;
 
@@ -68,6 +71,17 @@ where
         self.length
     }
 
+    /// Returns `true` if the `List` holds no `Node`s
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Creates an iterator over copies of the values of the `List` from the
+    /// start (head) to the end (tail)
+    pub fn iter(&self) -> ListIterator<'_, T> {
+        ListIterator::new(self.head.clone())
+    }
+
     /// Appends a value to the end (tail) of the `List`
     pub fn append(&mut self, value: T) {
         let node = Node::new(value);
@@ -86,10 +100,37 @@ where
         self.tail = Some(node);
     }
 
+    /// Prepends a value to the start (head) of the `List`
+    pub fn prepend(&mut self, value: T) {
+        let node = Node::new(value);
+
+        match self.head.take() {
+            Some(head_node) => {
+                head_node.borrow_mut().prev = Some(Rc::downgrade(&node));
+                node.borrow_mut().next = Some(head_node);
+            }
+            None => self.tail = Some(Rc::clone(&node)),
+        }
+
+        self.length += 1;
+        self.head = Some(node);
+    }
+
+    /// Retrieves a copy of the value at the start (head) of the `List`
+    pub fn front(&self) -> Option<T> {
+        self.head.as_ref().map(|head| head.borrow().value.clone())
+    }
+
+    /// Retrieves a copy of the value at the end (tail) of the `List`
+    pub fn back(&self) -> Option<T> {
+        self.tail.as_ref().map(|tail| tail.borrow().value.clone())
+    }
+
     /// Pops a value from the start (head) of the `List`
     pub fn pop(&mut self) -> Option<T> {
         self.head.take().map(|head| {
             if let Some(next) = head.borrow_mut().next.take() {
+                next.borrow_mut().prev.take();
                 self.head = Some(next);
             } else {
                 self.tail.take();
@@ -104,25 +145,58 @@ where
                 .value
         })
     }
+
+    /// Pops a value from the end (tail) of the `List`
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.take().map(|tail| {
+            match tail
+                .borrow_mut()
+                .prev
+                .take()
+                .and_then(|prev| prev.upgrade())
+            {
+                Some(prev) => {
+                    prev.borrow_mut().next.take();
+                    self.tail = Some(prev);
+                }
+                None => {
+                    self.head.take();
+                }
+            }
+
+            self.length -= 1;
+
+            Rc::try_unwrap(tail)
+                .unwrap_or_else(|_| panic!("Failed to retrieve node from list"))
+                .into_inner()
+                .value
+        })
+    }
 }
 
-pub struct ListIterator<T>
+/// An iterator over copies of the values of a `DoublyLinkedList`, which
+/// borrows the list so it can't be modified while the iterator is alive
+pub struct ListIterator<'a, T>
 where
     T: Clone + Debug,
 {
     current: Link<T>,
+    list: PhantomData<&'a DoublyLinkedList<T>>,
 }
 
-impl<T> ListIterator<T>
+impl<'a, T> ListIterator<'a, T>
 where
     T: Clone + Debug,
 {
     fn new(current: Link<T>) -> Self {
-        ListIterator { current }
+        ListIterator {
+            current,
+            list: PhantomData,
+        }
     }
 }
 
-impl<T> Iterator for ListIterator<T>
+impl<'a, T> Iterator for ListIterator<'a, T>
 where
     T: Clone + std::fmt::Debug,
 {
@@ -146,7 +220,7 @@ where
     }
 }
 
-impl<T> DoubleEndedIterator for ListIterator<T>
+impl<'a, T> DoubleEndedIterator for ListIterator<'a, T>
 where
     T: Clone + std::fmt::Debug,
 {
@@ -172,6 +246,169 @@ where
     }
 }
 
+impl<T> Default for DoublyLinkedList<T>
+where
+    T: Clone + Debug,
+{
+    fn default() -> Self {
+        DoublyLinkedList::new()
+    }
+}
+
+/// An owned iterator over the values of a `DoublyLinkedList`, which pops
+/// values from the `head` going forward and from the `tail` going backwards
+pub struct ListIntoIterator<T>
+where
+    T: Clone + Debug,
+{
+    list: DoublyLinkedList<T>,
+}
+
+impl<T> Iterator for ListIntoIterator<T>
+where
+    T: Clone + Debug,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let length = self.list.len() as usize;
+
+        (length, Some(length))
+    }
+}
+
+impl<T> DoubleEndedIterator for ListIntoIterator<T>
+where
+    T: Clone + Debug,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> IntoIterator for DoublyLinkedList<T>
+where
+    T: Clone + Debug,
+{
+    type Item = T;
+    type IntoIter = ListIntoIterator<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        ListIntoIterator { list: self }
+    }
+}
+
+/// Two lists are equal when they hold equal values in the same order
+impl<T> PartialEq for DoublyLinkedList<T>
+where
+    T: Clone + Debug + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        if self.length != other.length {
+            return false;
+        }
+
+        let mut current = self.head.clone();
+        let mut other_current = other.head.clone();
+
+        while let (Some(node), Some(other_node)) = (current, other_current) {
+            if node.borrow().value != other_node.borrow().value {
+                return false;
+            }
+
+            current = node.borrow().next.clone();
+            other_current = other_node.borrow().next.clone();
+        }
+
+        true
+    }
+}
+
+impl<T> Eq for DoublyLinkedList<T> where T: Clone + Debug + Eq {}
+
+/// Hashes the length and the values of the list from `head` to `tail`, so
+/// equal lists produce equal hashes
+impl<T> std::hash::Hash for DoublyLinkedList<T>
+where
+    T: Clone + Debug + std::hash::Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.length.hash(state);
+
+        let mut current = self.head.clone();
+
+        while let Some(node) = current {
+            node.borrow().value.hash(state);
+            current = node.borrow().next.clone();
+        }
+    }
+}
+
+impl<T> Collection<T> for DoublyLinkedList<T>
+where
+    T: Clone + Debug + PartialEq,
+{
+    fn len(&self) -> usize {
+        self.length as usize
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        let mut current = self.head.clone();
+
+        while let Some(node) = current {
+            if node.borrow().value == *value {
+                return true;
+            }
+
+            current = node.borrow().next.clone();
+        }
+
+        false
+    }
+}
+
+/// Serializes the list as the sequence of its values from `head` to `tail`
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for DoublyLinkedList<T>
+where
+    T: Clone + Debug + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.length as usize))?;
+        let mut current = self.head.clone();
+
+        while let Some(node) = current {
+            seq.serialize_element(&node.borrow().value)?;
+            current = node.borrow().next.clone();
+        }
+
+        seq.end()
+    }
+}
+
+/// Deserializes a list from a sequence of values appending them in order
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for DoublyLinkedList<T>
+where
+    T: Clone + Debug + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut list = DoublyLinkedList::new();
+
+        for value in Vec::<T>::deserialize(deserializer)? {
+            list.append(value);
+        }
+
+        Ok(list)
+    }
+}
+
 mod tests {
     #[allow(unused_imports)]
     use super::*;
@@ -285,4 +522,191 @@ mod tests {
             ]
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_and_deserializes_the_list() {
+        let mut list = DoublyLinkedList::<String>::new();
+
+        list.append(String::from("Im the first"));
+        list.append(String::from("Im the second!"));
+        list.append(String::from("Im the third"));
+
+        let json = serde_json::to_string(&list).unwrap();
+
+        assert_eq!(json, r#"["Im the first","Im the second!","Im the third"]"#);
+
+        let restored: DoublyLinkedList<String> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.len(), 3);
+        assert_eq!(
+            ListIterator::new(restored.tail)
+                .rev()
+                .collect::<Vec<String>>(),
+            vec![
+                String::from("Im the third"),
+                String::from("Im the second!"),
+                String::from("Im the first"),
+            ]
+        );
+    }
+
+    #[test]
+    fn retrieves_the_front_value() {
+        let mut list = DoublyLinkedList::<String>::new();
+
+        assert_eq!(list.front(), None);
+
+        list.append(String::from("MyValue"));
+        list.append(String::from("MySecondValue"));
+
+        assert_eq!(list.front(), Some(String::from("MyValue")));
+
+        list.pop();
+
+        assert_eq!(list.front(), Some(String::from("MySecondValue")));
+    }
+
+    #[test]
+    fn prepends_a_value_to_the_list() {
+        let mut list = DoublyLinkedList::<String>::new();
+
+        list.prepend(String::from("Im the second!"));
+        list.prepend(String::from("Im the first"));
+        list.append(String::from("Im the third"));
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(
+            ListIterator::new(list.tail.clone())
+                .rev()
+                .collect::<Vec<String>>(),
+            vec![
+                String::from("Im the third"),
+                String::from("Im the second!"),
+                String::from("Im the first"),
+            ]
+        );
+        assert_eq!(
+            ListIterator::new(list.head).collect::<Vec<String>>(),
+            vec![
+                String::from("Im the first"),
+                String::from("Im the second!"),
+                String::from("Im the third"),
+            ]
+        );
+    }
+
+    #[test]
+    fn pops_a_node_from_the_end_of_the_list() {
+        let mut list = DoublyLinkedList::<String>::new();
+
+        list.append(String::from("MyValue"));
+        list.append(String::from("MySecondValue"));
+        list.append(String::from("MyLastValue"));
+
+        assert_eq!(list.pop_back(), Some(String::from("MyLastValue")));
+        assert_eq!(list.back(), Some(String::from("MySecondValue")));
+        assert_eq!(list.pop(), Some(String::from("MyValue")));
+        assert_eq!(list.pop_back(), Some(String::from("MySecondValue")));
+        assert_eq!(list.pop_back(), None);
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn creates_an_empty_list_by_default() {
+        let list: DoublyLinkedList<String> = Default::default();
+
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn consumes_the_list_from_both_ends() {
+        let mut list = DoublyLinkedList::<u32>::new();
+
+        for value in 1..=4 {
+            list.append(value);
+        }
+
+        let mut iter = list.into_iter();
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+
+        while let Some(value) = iter.next() {
+            front.push(value);
+
+            match iter.next_back() {
+                Some(value) => back.push(value),
+                None => break,
+            }
+        }
+
+        assert_eq!(front, vec![1, 2]);
+        assert_eq!(back, vec![4, 3]);
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn consumes_the_list_in_reverse() {
+        let mut list = DoublyLinkedList::<String>::new();
+
+        list.append(String::from("Im the first"));
+        list.append(String::from("Im the second!"));
+        list.append(String::from("Im the third"));
+
+        assert_eq!(
+            list.into_iter().rev().collect::<Vec<String>>(),
+            vec![
+                String::from("Im the third"),
+                String::from("Im the second!"),
+                String::from("Im the first"),
+            ]
+        );
+    }
+
+    #[test]
+    fn hashes_equal_lists_equally() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of(list: &DoublyLinkedList<u32>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+
+            list.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let mut list = DoublyLinkedList::new();
+        let mut other = DoublyLinkedList::new();
+        let mut reversed = DoublyLinkedList::new();
+
+        for value in 1..=3 {
+            list.append(value);
+            other.append(value);
+            reversed.prepend(value);
+        }
+
+        assert!(list == other);
+        assert!(list != reversed);
+        assert_eq!(hash_of(&list), hash_of(&other));
+        assert_ne!(hash_of(&list), hash_of(&reversed));
+    }
+
+    #[test]
+    fn iterates_on_the_values_of_the_list() {
+        let mut list = DoublyLinkedList::<u32>::new();
+
+        assert_eq!(list.iter().count(), 0);
+
+        for value in 1..=3 {
+            list.append(value);
+        }
+
+        assert_eq!(list.iter().collect::<Vec<u32>>(), vec![1, 2, 3]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pop(), Some(1));
+    }
 }
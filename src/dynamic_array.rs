@@ -6,7 +6,26 @@
 //! This implementation makes use of OpenJDK 8 `ArrayList` class,
 //! `grow` method algorithm to "grow" the underlying array when inserting
 //! more items than the current `cap` value.
-use std::cmp;
+//!
+//! When the array grows from an empty buffer, at least `DEFAULT_CAPACITY`
+//! slots are allocated, then every growth extends the capacity by half
+//! its current value (e.g. `4`, `6`, `9`, `13`, ...).
+//!
+//! Only `core` and `alloc` are used, so the array is available when the
+//! crate is built without the `std` feature.
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp;
+use core::ops::{Index, IndexMut};
+
+use crate::traits::Collection;
+
+/// The minimum capacity allocated when the array grows from an empty buffer,
+/// as OpenJDK 8 `ArrayList` does with its `DEFAULT_CAPACITY`
+const DEFAULT_CAPACITY: usize = 4;
 
 pub struct DynamicArray<T: Clone> {
     buffer: Box<[Option<T>]>,
@@ -26,6 +45,32 @@ impl<T: Clone> DynamicArray<T> {
         }
     }
 
+    /// Creates a new `DynamicArray` able to hold `cap` items without
+    /// growing
+    pub fn with_capacity(cap: usize) -> Self {
+        DynamicArray {
+            buffer: vec![None; cap].into_boxed_slice(),
+            length: 0,
+            capacity: cap,
+        }
+    }
+
+    /// Retrieves the count of items in the array
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Retrieves the count of items the array is able to hold without
+    /// growing
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns `true` if the array holds no items
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
     /// This is a Rust implementation of OpenJDK 8 ArrayList.grow method
     ///
     /// Source code is available here: https://hg.openjdk.java.net/jdk8/jdk8/jdk/file/tip/src/share/classes/java/util/ArrayList.java#l237
@@ -33,6 +78,10 @@ impl<T: Clone> DynamicArray<T> {
         let current_capacity = self.buffer.len();
         let mut extended_capacity = current_capacity + (current_capacity >> 1);
 
+        if current_capacity == 0 {
+            extended_capacity = DEFAULT_CAPACITY;
+        }
+
         extended_capacity = cmp::max(extended_capacity, min_cap);
         extended_capacity = cmp::min(extended_capacity, usize::MAX);
         self.capacity = extended_capacity;
@@ -49,7 +98,9 @@ impl<T: Clone> DynamicArray<T> {
         }
     }
 
-    pub fn item_at(&mut self, index: usize) -> Option<T> {
+    /// Retrieves a copy of the item at `index`, returns `None` if the
+    /// `index` is out of range
+    pub fn item_at(&self, index: usize) -> Option<T> {
         if self.length > index {
             return self.buffer[index].clone();
         }
@@ -57,90 +108,1615 @@ impl<T: Clone> DynamicArray<T> {
         None
     }
 
+    /// Retrieves a copy of the first item, returns `None` if the array is
+    /// empty
+    pub fn first(&self) -> Option<T> {
+        self.item_at(0)
+    }
+
+    /// Retrieves a copy of the last item, returns `None` if the array is
+    /// empty
+    pub fn last(&self) -> Option<T> {
+        self.length
+            .checked_sub(1)
+            .and_then(|index| self.item_at(index))
+    }
+
+    /// Retrieves a mutable reference to the item at `index`, returns `None`
+    /// if the `index` is out of range
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if self.length > index {
+            return self.buffer[index].as_mut();
+        }
+
+        None
+    }
+
+    /// Replaces the item at `index` with `value`.
+    ///
+    /// Unlike `add`, this never grows the array and returns `Err` if the
+    /// `index` is out of range
+    pub fn set(&mut self, index: usize, value: T) -> Result<(), String> {
+        if index >= self.length {
+            return Err(format!(
+                "Index out of bounds: the length is {} but the index is {}",
+                self.length, index
+            ));
+        }
+
+        self.buffer[index] = Some(value);
+
+        Ok(())
+    }
+
     pub fn add(&mut self, value: T) {
         self.ensure_capacity(1);
         self.buffer[self.length] = Some(value);
         self.length += 1;
     }
-}
 
-pub struct DynamicArrayIterator<T: Clone> {
-    current: usize,
-    data: Box<[T]>,
-}
+    /// Removes the last item from the array, returns `None` if the array
+    /// is empty
+    pub fn pop(&mut self) -> Option<T> {
+        if self.length == 0 {
+            return None;
+        }
 
-impl<T: Clone> Iterator for DynamicArrayIterator<T> {
-    type Item = T;
+        self.length -= 1;
+        self.buffer[self.length].take()
+    }
 
-    fn next(&mut self) -> Option<T> {
-        if self.current < self.data.len() {
-            let item = self.data[self.current].clone();
-            self.current += 1;
+    /// Inserts `value` at `index` shifting every subsequent item one
+    /// position to the right.
+    ///
+    /// Inserting at the current length of the array is equivalent to `add`.
+    /// Inserting at the start of the array shifts every item, so pushing
+    /// many items to the front is better done by adding them and then
+    /// calling `rotate_right`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the array
+    pub fn insert(&mut self, index: usize, value: T) {
+        if index > self.length {
+            panic!(
+                "Insertion index (is {}) should be <= length (is {})",
+                index, self.length
+            );
+        }
 
-            return Some(item);
+        self.ensure_capacity(1);
+        self.buffer[self.length] = Some(value);
+
+        // moves the new item from the end of the live items to `index`
+        self.buffer[index..=self.length].rotate_right(1);
+        self.length += 1;
+    }
+
+    /// Removes the item at `index` shifting every subsequent item one
+    /// position to the left.
+    ///
+    /// Returns `None` if the `index` is out of range
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.length {
+            return None;
         }
 
-        None
+        let item = self.buffer[index].take();
+
+        // moves the now empty slot to the end of the live items
+        self.buffer[index..self.length].rotate_left(1);
+        self.length -= 1;
+
+        item
+    }
+
+    /// Shrinks the capacity of the array to match its length
+    pub fn shrink_to_fit(&mut self) {
+        if self.capacity == self.length {
+            return;
+        }
+
+        let mut buffer = core::mem::take(&mut self.buffer).into_vec();
+
+        buffer.truncate(self.length);
+        self.buffer = buffer.into_boxed_slice();
+        self.capacity = self.length;
+    }
+
+    /// Removes every item from the array keeping its capacity
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Removes every item after the first `new_len` items keeping the
+    /// capacity of the array.
+    ///
+    /// Has no effect if `new_len` is greater than the length of the array
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len >= self.length {
+            return;
+        }
+
+        for slot in self.buffer[new_len..self.length].iter_mut() {
+            *slot = None;
+        }
+
+        self.length = new_len;
+    }
+
+    /// Swaps the items at `i` and `j`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either `i` or `j` is out of range
+    pub fn swap(&mut self, i: usize, j: usize) {
+        let index = cmp::max(i, j);
+
+        if index >= self.length {
+            panic!(
+                "Index out of bounds: the length is {} but the index is {}",
+                self.length, index
+            );
+        }
+
+        self.buffer.swap(i, j);
+    }
+
+    /// Removes the item at `index` replacing it with the last item of the
+    /// array.
+    ///
+    /// This doesn't preserve the order of the items but runs in constant
+    /// time, returns `None` if the `index` is out of range
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.length {
+            return None;
+        }
+
+        self.buffer.swap(index, self.length - 1);
+        self.pop()
+    }
+
+    /// Keeps only the items for which `f` returns `true`, preserving their
+    /// order
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, mut f: F) {
+        let mut retained = 0;
+
+        for index in 0..self.length {
+            let keep = match self.buffer[index] {
+                Some(ref item) => f(item),
+                None => false,
+            };
+
+            if keep {
+                // moves the item next to the last retained item, the slot
+                // swapped in is always empty
+                self.buffer.swap(retained, index);
+                retained += 1;
+            } else {
+                self.buffer[index] = None;
+            }
+        }
+
+        self.length = retained;
+    }
+
+    /// Appends every item from `items` to the end of the array.
+    ///
+    /// When the count of items is known ahead, the array grows once to fit
+    /// them all
+    pub fn add_all(&mut self, items: impl IntoIterator<Item = T>) {
+        let items = items.into_iter();
+
+        if let (lower, Some(upper)) = items.size_hint() {
+            if lower == upper {
+                self.ensure_capacity(lower);
+            }
+        }
+
+        for item in items {
+            self.add(item);
+        }
+    }
+
+    /// Reserves capacity for at least `additional` more items to be added
+    /// to the array without growing
+    pub fn reserve(&mut self, additional: usize) {
+        self.ensure_capacity(additional);
+    }
+
+    /// Resizes the array to hold `new_len` items.
+    ///
+    /// If `new_len` is greater than the length of the array, the new slots
+    /// are filled with copies of `value`, otherwise the array is truncated
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        if new_len <= self.length {
+            self.truncate(new_len);
+            return;
+        }
+
+        self.ensure_capacity(new_len - self.length);
+
+        for slot in self.buffer[self.length..new_len].iter_mut() {
+            *slot = Some(value.clone());
+        }
+
+        self.length = new_len;
+    }
+
+    /// Replaces every item of the array with a copy of `value`
+    pub fn fill(&mut self, value: T) {
+        for slot in self.buffer[..self.length].iter_mut() {
+            *slot = Some(value.clone());
+        }
+    }
+
+    /// Removes the items in `range` from the array, returning them in a
+    /// `Vec`. The remaining items are shifted to fill the gap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the `range` is greater than its end or if the
+    /// end of the `range` is greater than the length of the array
+    pub fn drain(&mut self, range: core::ops::Range<usize>) -> Vec<T> {
+        if range.start > range.end || range.end > self.length {
+            panic!(
+                "Range {:?} out of bounds for array of length {}",
+                range, self.length
+            );
+        }
+
+        let count = range.len();
+        let drained = self.buffer[range.clone()]
+            .iter_mut()
+            .filter_map(Option::take)
+            .collect();
+
+        // moves the now empty slots to the end of the live items
+        self.buffer[range.start..self.length].rotate_left(count);
+        self.length -= count;
+
+        drained
+    }
+
+    /// Splits the array in two at `index`, returning a new array with the
+    /// items from `index` to the end while `self` keeps the items before
+    /// `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length of the array
+    pub fn split_off(&mut self, index: usize) -> DynamicArray<T> {
+        DynamicArray::from(self.drain(index..self.length))
+    }
+
+    /// Retrieves the slots holding the items of the array
+    pub fn as_slice(&self) -> &[Option<T>] {
+        &self.buffer[..self.length]
+    }
+
+    /// Copies the items of the array into a `Vec`
+    pub fn to_vec(&self) -> Vec<T> {
+        self.as_slice().iter().flatten().cloned().collect()
+    }
+
+    /// Rotates the items of the array in place so the item at `mid` becomes
+    /// the first item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid` is greater than the length of the array
+    pub fn rotate_left(&mut self, mid: usize) {
+        self.buffer[..self.length].rotate_left(mid);
+    }
+
+    /// Rotates the items of the array in place so the last `k` items
+    /// become the first items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the length of the array
+    pub fn rotate_right(&mut self, k: usize) {
+        self.buffer[..self.length].rotate_right(k);
+    }
+
+    /// Reverses the order of the items of the array in place
+    pub fn reverse(&mut self) {
+        let mut front = 0;
+        let mut back = self.length;
+
+        while front + 1 < back {
+            back -= 1;
+            self.buffer.swap(front, back);
+            front += 1;
+        }
+    }
+
+    /// Consumes the array splitting its items into the ones for which
+    /// `pred` returns `true` and the ones for which it returns `false`,
+    /// preserving the order of the items on each array
+    pub fn partition<F: Fn(&T) -> bool>(self, pred: F) -> (DynamicArray<T>, DynamicArray<T>) {
+        let mut matching = DynamicArray::new();
+        let mut rest = DynamicArray::new();
+
+        for item in self {
+            if pred(&item) {
+                matching.add(item);
+            } else {
+                rest.add(item);
+            }
+        }
+
+        (matching, rest)
+    }
+
+    /// Creates an iterator over copies of `size` consecutive items of the
+    /// array, chunks don't overlap and the last chunk may be shorter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = Vec<T>> + '_ {
+        self.buffer[..self.length]
+            .chunks(size)
+            .map(|chunk| chunk.iter().flatten().cloned().collect())
+    }
+
+    /// Creates an iterator over copies of every `size` consecutive items of
+    /// the array, windows overlap and no window is yielded if the array
+    /// holds less than `size` items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = Vec<T>> + '_ {
+        self.buffer[..self.length]
+            .windows(size)
+            .map(|window| window.iter().flatten().cloned().collect())
+    }
+
+    /// Creates an array pairing copies of the items of this array with the
+    /// items of `other` at the same position, up to the length of the
+    /// shortest of both
+    pub fn zip<U: Clone>(&self, other: &DynamicArray<U>) -> DynamicArray<(T, U)> {
+        self.into_iter()
+            .zip(other)
+            .map(|(a, b)| (a.clone(), b.clone()))
+            .collect()
+    }
+
+    /// Creates an iterator over the items of the array
+    pub fn iter(&self) -> DynamicArrayIterator<T> {
+        DynamicArrayIterator::new(self.to_vec().into_boxed_slice())
+    }
+
+    /// Creates an iterator over mutable references to the items of the
+    /// array
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.buffer[..self.length].iter_mut().flatten()
     }
 }
 
-impl<T: Clone> DoubleEndedIterator for DynamicArrayIterator<T> {
-    fn next_back(&mut self) -> Option<Self::Item> {
-        if self.current < self.data.len() {
-            let item = self.data[self.current].clone();
+impl<T: Clone + PartialEq> DynamicArray<T> {
+    /// Returns `true` if the array holds an item equal to `value`
+    pub fn contains(&self, value: &T) -> bool {
+        self.index_of(value).is_some()
+    }
+
+    /// Retrieves the index of the first item equal to `value`
+    pub fn index_of(&self, value: &T) -> Option<usize> {
+        self.buffer[..self.length]
+            .iter()
+            .position(|item| item.as_ref() == Some(value))
+    }
+
+    /// Swaps the first item equal to `a` with the first item equal to `b`.
+    ///
+    /// Returns `false` and leaves the array untouched if either value is
+    /// not found
+    pub fn swap_values(&mut self, a: &T, b: &T) -> bool {
+        match (self.index_of(a), self.index_of(b)) {
+            (Some(i), Some(j)) => {
+                self.swap(i, j);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Removes consecutive repeated items from the array, keeping the first
+    /// item of each run
+    pub fn dedup(&mut self) {
+        if self.length == 0 {
+            return;
+        }
+
+        let mut retained = 1;
 
-            if self.current == 0 {
-                self.current = self.data.len() - 1;
+        for index in 1..self.length {
+            if self.buffer[index] == self.buffer[retained - 1] {
+                self.buffer[index] = None;
             } else {
-                self.current -= 1;
+                // the slot swapped in is always empty
+                self.buffer.swap(retained, index);
+                retained += 1;
             }
+        }
 
-            return Some(item);
+        self.length = retained;
+    }
+}
+
+impl<T: Clone + Ord> DynamicArray<T> {
+    /// Sorts the items of the array in ascending order
+    pub fn sort(&mut self) {
+        // every live slot holds `Some` item, so sorting the slots sorts
+        // the items
+        self.buffer[..self.length].sort();
+    }
+
+    /// Returns `true` if every item of the array is lower than or equal to
+    /// the next one
+    pub fn is_sorted(&self) -> bool {
+        self.buffer[..self.length]
+            .windows(2)
+            .all(|pair| pair[0] <= pair[1])
+    }
+
+    /// Binary searches the array for `target`, the items of the array are
+    /// expected to be sorted.
+    ///
+    /// If the `target` is found returns `Ok` with its index, otherwise
+    /// returns `Err` with the index where the `target` could be inserted
+    /// keeping the array sorted
+    pub fn binary_search(&self, target: &T) -> Result<usize, usize> {
+        let mut low = 0;
+        let mut high = self.length;
+
+        while low < high {
+            let middle = low + (high - low) / 2;
+
+            match self[middle].cmp(target) {
+                cmp::Ordering::Less => low = middle + 1,
+                cmp::Ordering::Equal => return Ok(middle),
+                cmp::Ordering::Greater => high = middle,
+            }
         }
 
-        None
+        Err(low)
     }
 }
 
-mod tests {
-    #[allow(unused_imports)]
-    use super::*;
+#[cfg(feature = "std")]
+impl<T: Clone + Ord + core::fmt::Debug> DynamicArray<T> {
+    /// Builds a height balanced `BinarySearchTree` from the items of the
+    /// array.
+    ///
+    /// The items are sorted and the middle item is picked as the root of
+    /// the tree, then the middle item of each half is picked as the root of
+    /// each sub-tree
+    pub fn to_balanced_bst(mut self) -> crate::binary_search_tree::BinarySearchTree<T> {
+        let mut bst = crate::binary_search_tree::BinarySearchTree::new();
 
-    #[test]
-    fn extends_internal_buffer_size() {
-        let mut list = DynamicArray::<String>::new();
+        self.sort();
+        bst.add_balanced(&self.to_vec());
 
-        assert_eq!(list.capacity, 0);
-        assert_eq!(list.length, 0);
-        assert_eq!(list.buffer.len(), 0);
+        bst
+    }
+}
 
-        list.grow(10);
+impl<T: Clone> DynamicArray<DynamicArray<T>> {
+    /// Consumes the array of arrays concatenating the items of each inner
+    /// array, in order, into a single array
+    pub fn flatten(self) -> DynamicArray<T> {
+        let mut flattened = DynamicArray::new();
 
-        assert_eq!(list.capacity, 10);
-        assert_eq!(list.length, 0);
-        assert_eq!(list.buffer.len(), 10);
+        for inner in self {
+            flattened.add_all(inner);
+        }
+
+        flattened
     }
+}
 
-    #[test]
-    fn adds_item_at_the_end_of_the_array() {
-        let mut list = DynamicArray::<String>::new();
+impl<T: Clone> Default for DynamicArray<T> {
+    fn default() -> Self {
+        DynamicArray::new()
+    }
+}
 
-        list.add(String::from("foo"));
-        list.add(String::from("bar"));
+impl<T: Clone + PartialEq> Collection<T> for DynamicArray<T> {
+    fn len(&self) -> usize {
+        self.length
+    }
 
-        assert_eq!(list.capacity, 2);
-        assert_eq!(list.length, 2);
+    fn contains(&self, value: &T) -> bool {
+        self.index_of(value).is_some()
     }
+}
 
-    #[test]
-    fn gets_items_from_array() {
-        let mut list = DynamicArray::<String>::new();
+impl<T: Clone> IntoIterator for DynamicArray<T> {
+    type Item = T;
+    type IntoIter = DynamicArrayIterator<T>;
 
-        list.add(String::from("foo"));
-        list.add(String::from("bar"));
+    fn into_iter(self) -> Self::IntoIter {
+        let data: Vec<T> = self
+            .buffer
+            .into_vec()
+            .into_iter()
+            .take(self.length)
+            .flatten()
+            .collect();
 
-        assert_eq!(list.item_at(0), Some(String::from("foo")));
-        assert_eq!(list.item_at(1), Some(String::from("bar")));
-        assert_eq!(list.item_at(2), None);
+        DynamicArrayIterator::new(data.into_boxed_slice())
+    }
+}
+
+/// Iterates over references to the items of the array without cloning them
+impl<'a, T: Clone> IntoIterator for &'a DynamicArray<T> {
+    type Item = &'a T;
+    type IntoIter = core::iter::Flatten<core::slice::Iter<'a, Option<T>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.buffer[..self.length].iter().flatten()
+    }
+}
+
+impl<T: Clone> From<Vec<T>> for DynamicArray<T> {
+    fn from(items: Vec<T>) -> Self {
+        let length = items.len();
+        let buffer: Vec<Option<T>> = items.into_iter().map(Some).collect();
+
+        DynamicArray {
+            buffer: buffer.into_boxed_slice(),
+            length,
+            capacity: length,
+        }
+    }
+}
+
+impl<T: Clone> core::iter::FromIterator<T> for DynamicArray<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        DynamicArray::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<T: Clone> Extend<T> for DynamicArray<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.add_all(iter);
+    }
+}
+
+impl<T: Clone> Clone for DynamicArray<T> {
+    fn clone(&self) -> Self {
+        let buffer: Vec<Option<T>> = self.buffer[..self.length].to_vec();
+
+        DynamicArray {
+            buffer: buffer.into_boxed_slice(),
+            length: self.length,
+            capacity: self.length,
+        }
+    }
+}
+
+impl<T: Clone + core::fmt::Debug> core::fmt::Debug for DynamicArray<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_list()
+            .entries(self.buffer[..self.length].iter().flatten())
+            .finish()
+    }
+}
+
+impl<T: Clone + PartialEq> PartialEq for DynamicArray<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Clone + Eq> Eq for DynamicArray<T> {}
+
+/// Hashes the length and the items of the array in order, so equal arrays
+/// produce equal hashes regardless of their capacity
+impl<T: Clone + core::hash::Hash> core::hash::Hash for DynamicArray<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.length.hash(state);
+
+        for item in self {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T: Clone> Index<usize> for DynamicArray<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        if index >= self.length {
+            panic!(
+                "Index out of bounds: the length is {} but the index is {}",
+                self.length, index
+            );
+        }
+
+        self.buffer[index]
+            .as_ref()
+            .expect("Failed to retrieve item from array")
+    }
+}
+
+impl<T: Clone> IndexMut<usize> for DynamicArray<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        if index >= self.length {
+            panic!(
+                "Index out of bounds: the length is {} but the index is {}",
+                self.length, index
+            );
+        }
+
+        self.buffer[index]
+            .as_mut()
+            .expect("Failed to retrieve item from array")
+    }
+}
+
+/// An iterator over the items of a `DynamicArray`.
+///
+/// Items are consumed from the front by `next` and from the back by
+/// `next_back`, the iterator is exhausted when both cursors meet
+pub struct DynamicArrayIterator<T: Clone> {
+    front: usize,
+    back: usize,
+    data: Box<[T]>,
+}
+
+impl<T: Clone> DynamicArrayIterator<T> {
+    fn new(data: Box<[T]>) -> Self {
+        DynamicArrayIterator {
+            front: 0,
+            back: data.len(),
+            data,
+        }
+    }
+}
+
+impl<T: Clone> Iterator for DynamicArrayIterator<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front < self.back {
+            let item = self.data[self.front].clone();
+            self.front += 1;
+
+            return Some(item);
+        }
+
+        None
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for DynamicArrayIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+
+            return Some(self.data[self.back].clone());
+        }
+
+        None
+    }
+}
+
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn extends_internal_buffer_size() {
+        let mut list = DynamicArray::<String>::new();
+
+        assert_eq!(list.capacity, 0);
+        assert_eq!(list.length, 0);
+        assert_eq!(list.buffer.len(), 0);
+
+        list.grow(10);
+
+        assert_eq!(list.capacity, 10);
+        assert_eq!(list.length, 0);
+        assert_eq!(list.buffer.len(), 10);
+    }
+
+    #[test]
+    fn adds_item_at_the_end_of_the_array() {
+        let mut list = DynamicArray::<String>::new();
+
+        list.add(String::from("foo"));
+        list.add(String::from("bar"));
+
+        assert_eq!(list.capacity, 4);
+        assert_eq!(list.length, 2);
+    }
+
+    #[test]
+    fn gets_items_from_array() {
+        let mut list = DynamicArray::<String>::new();
+
+        list.add(String::from("foo"));
+        list.add(String::from("bar"));
+
+        assert_eq!(list.item_at(0), Some(String::from("foo")));
+        assert_eq!(list.item_at(1), Some(String::from("bar")));
+        assert_eq!(list.item_at(2), None);
+    }
+
+    #[test]
+    fn iterates_over_array_items() {
+        let mut list = DynamicArray::<String>::new();
+
+        list.add(String::from("foo"));
+        list.add(String::from("bar"));
+        list.add(String::from("baz"));
+
+        let items: Vec<String> = list.iter().collect();
+        let expect = vec![
+            String::from("foo"),
+            String::from("bar"),
+            String::from("baz"),
+        ];
+
+        assert_eq!(items, expect);
+        assert_eq!(list.into_iter().collect::<Vec<String>>(), expect);
+    }
+
+    #[test]
+    fn iterates_backwards() {
+        let mut list = DynamicArray::<String>::new();
+
+        list.add(String::from("a"));
+        list.add(String::from("b"));
+        list.add(String::from("c"));
+
+        let items: Vec<String> = list.iter().rev().collect();
+
+        assert_eq!(
+            items,
+            vec![String::from("c"), String::from("b"), String::from("a")]
+        );
+    }
+
+    #[test]
+    fn iterates_from_both_ends_once() {
+        let mut list = DynamicArray::<u32>::new();
+
+        list.add(1);
+        list.add(2);
+        list.add(3);
+
+        let mut iter = list.iter();
+
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next_back(), Some(2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn removes_items_from_array() {
+        let mut list = DynamicArray::<u32>::new();
+
+        list.add(1);
+        list.add(2);
+        list.add(3);
+        list.add(4);
+        list.add(5);
+
+        assert_eq!(list.remove(0), Some(1));
+        assert_eq!(list.remove(3), Some(5));
+        assert_eq!(list.remove(1), Some(3));
+        assert_eq!(list.remove(2), None);
+        assert_eq!(list.length, 2);
+        assert_eq!(list.buffer[2], None);
+        assert_eq!(list.iter().collect::<Vec<u32>>(), vec![2, 4]);
+    }
+
+    #[test]
+    fn inserts_items_into_array() {
+        let mut list = DynamicArray::<u32>::new();
+
+        list.add(2);
+        list.add(4);
+
+        list.insert(0, 1);
+        list.insert(2, 3);
+        list.insert(4, 5);
+
+        assert_eq!(list.length, 5);
+        assert_eq!(list.iter().collect::<Vec<u32>>(), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_when_inserting_out_of_range() {
+        let mut list = DynamicArray::<u32>::new();
+
+        list.add(1);
+        list.insert(2, 2);
+    }
+
+    #[test]
+    fn pops_items_from_array() {
+        let mut list = DynamicArray::<String>::new();
+
+        list.add(String::from("foo"));
+        list.add(String::from("bar"));
+
+        assert_eq!(list.pop(), Some(String::from("bar")));
+        assert_eq!(list.item_at(1), None);
+        assert_eq!(list.pop(), Some(String::from("foo")));
+        assert_eq!(list.item_at(0), None);
+        assert_eq!(list.pop(), None);
+        assert_eq!(list.length, 0);
+        assert_eq!(list.capacity, 4);
+    }
+
+    #[test]
+    fn reads_and_assigns_items_by_index() {
+        let mut list = DynamicArray::<String>::new();
+
+        list.add(String::from("foo"));
+        list.add(String::from("bar"));
+        list.add(String::from("baz"));
+
+        list[1] = String::from("qux");
+
+        assert_eq!(list[0], String::from("foo"));
+        assert_eq!(list[1], String::from("qux"));
+        assert_eq!(list[2], String::from("baz"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_when_indexing_out_of_range() {
+        let mut list = DynamicArray::<u32>::new();
+
+        list.add(1);
+        list.add(2);
+        list.add(3);
+        list.pop();
+
+        // the slot is allocated but is not a live item anymore
+        let _ = list[2];
+    }
+
+    #[test]
+    fn gets_items_from_a_shared_array() {
+        let mut list = DynamicArray::<String>::new();
+
+        list.add(String::from("foo"));
+        list.add(String::from("bar"));
+
+        let first_reference = &list;
+        let second_reference = &list;
+
+        assert_eq!(first_reference.item_at(0), Some(String::from("foo")));
+        assert_eq!(second_reference.item_at(1), Some(String::from("bar")));
+        assert_eq!(first_reference.item_at(2), None);
+    }
+
+    #[test]
+    fn retrieves_length_and_capacity() {
+        let mut list = DynamicArray::<u32>::new();
+
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.capacity(), 0);
+
+        list.add(1);
+        list.add(2);
+        list.add(3);
+        list.add(4);
+
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.capacity(), 4);
+
+        // grows to `capacity + (capacity >> 1)`
+        list.add(5);
+
+        assert!(!list.is_empty());
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.capacity(), 6);
+
+        list.add(6);
+        list.add(7);
+
+        assert_eq!(list.len(), 7);
+        assert_eq!(list.capacity(), 9);
+    }
+
+    #[test]
+    fn creates_an_array_with_capacity() {
+        let mut list = DynamicArray::<u32>::with_capacity(16);
+
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.capacity(), 16);
+        assert_eq!(list.buffer.len(), 16);
+
+        for item in 0..10 {
+            list.add(item);
+        }
+
+        assert_eq!(list.len(), 10);
+        assert_eq!(list.capacity(), 16);
+        assert_eq!(list.buffer.len(), 16);
+    }
+
+    #[test]
+    fn shrinks_capacity_to_fit_length() {
+        let mut list = DynamicArray::<u32>::new();
+
+        for item in 0..10 {
+            list.add(item);
+        }
+
+        assert!(list.capacity() > list.len());
+
+        list.shrink_to_fit();
+
+        assert_eq!(list.capacity(), 10);
+        assert_eq!(list.buffer.len(), 10);
+        assert_eq!(
+            list.iter().collect::<Vec<u32>>(),
+            (0..10).collect::<Vec<u32>>()
+        );
+    }
+
+    #[test]
+    fn clears_the_array() {
+        let mut list = DynamicArray::<u32>::new();
+
+        list.add(1);
+        list.add(2);
+        list.add(3);
+
+        list.clear();
+
+        assert!(list.is_empty());
+        assert_eq!(list.capacity(), 4);
+        assert_eq!(list.item_at(0), None);
+        assert!(list.buffer.iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn truncates_the_array() {
+        let mut list = DynamicArray::<u32>::new();
+
+        list.add(1);
+        list.add(2);
+        list.add(3);
+        list.add(4);
+
+        list.truncate(5);
+
+        assert_eq!(list.len(), 4);
+
+        list.truncate(2);
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.capacity(), 4);
+        assert_eq!(list.item_at(1), Some(2));
+        assert_eq!(list.item_at(2), None);
+        assert_eq!(list.buffer[2], None);
+    }
+
+    #[test]
+    fn creates_an_array_from_a_vec() {
+        let list = DynamicArray::from(vec![1, 2, 3]);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.capacity(), 3);
+        assert_eq!(list.item_at(0), Some(1));
+        assert_eq!(list.item_at(2), Some(3));
+        assert_eq!(list.item_at(3), None);
+        assert_eq!(list.iter().collect::<Vec<u32>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn collects_an_iterator_into_an_array() {
+        let list: DynamicArray<u32> = (1..=4).collect();
+
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.item_at(1), Some(2));
+        assert_eq!(list.iter().collect::<Vec<u32>>(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn finds_items_in_array() {
+        let list = DynamicArray::from(vec![1, 2, 3, 2]);
+
+        assert!(list.contains(&2));
+        assert!(!list.contains(&4));
+        assert_eq!(list.index_of(&2), Some(1));
+        assert_eq!(list.index_of(&3), Some(2));
+        assert_eq!(list.index_of(&4), None);
+    }
+
+    #[test]
+    fn mutates_items_through_iterator() {
+        let mut list = DynamicArray::from(vec![1, 2, 3]);
+
+        list.add(4);
+
+        for item in list.iter_mut() {
+            *item *= 2;
+        }
+
+        assert_eq!(list.iter().collect::<Vec<u32>>(), vec![2, 4, 6, 8]);
+    }
+
+    #[test]
+    fn swap_removes_items_from_array() {
+        let mut list = DynamicArray::from(vec![1, 2, 3, 4]);
+
+        assert_eq!(list.swap_remove(1), Some(2));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.item_at(1), Some(4));
+        assert_eq!(list.iter().collect::<Vec<u32>>(), vec![1, 4, 3]);
+        assert_eq!(list.swap_remove(2), Some(3));
+        assert_eq!(list.swap_remove(2), None);
+        assert_eq!(list.iter().collect::<Vec<u32>>(), vec![1, 4]);
+    }
+
+    #[test]
+    fn retains_items_matching_predicate() {
+        let mut list: DynamicArray<u32> = (1..=6).collect();
+
+        list.retain(|item| item % 2 == 0);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.capacity(), 6);
+        assert_eq!(list.iter().collect::<Vec<u32>>(), vec![2, 4, 6]);
+        assert!(list.buffer[3..].iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn extends_array_growing_once() {
+        let mut list = DynamicArray::<u32>::new();
+
+        list.add(0);
+        list.add_all(1..10);
+
+        assert_eq!(list.len(), 10);
+        assert_eq!(list.capacity(), 10);
+
+        list.extend(vec![10, 11, 12, 13, 14, 15, 16, 17, 18, 19]);
+
+        assert_eq!(list.len(), 20);
+        assert_eq!(list.capacity(), 20);
+        assert_eq!(
+            list.iter().collect::<Vec<u32>>(),
+            (0..20).collect::<Vec<u32>>()
+        );
+    }
+
+    #[test]
+    fn reserves_capacity() {
+        let mut list = DynamicArray::<u32>::new();
+
+        list.add(1);
+        list.reserve(10);
+
+        let capacity = list.capacity();
+
+        assert!(capacity >= 11);
+
+        for item in 2..=11 {
+            list.add(item);
+        }
+
+        assert_eq!(list.len(), 11);
+        assert_eq!(list.capacity(), capacity);
+
+        list.reserve(0);
+
+        assert_eq!(list.capacity(), capacity);
+    }
+
+    #[test]
+    fn clones_live_items_only() {
+        let mut list = DynamicArray::<u32>::with_capacity(8);
+
+        list.add(1);
+        list.add(2);
+
+        let mut copy = list.clone();
+
+        copy.add(3);
+        copy[0] = 10;
+
+        assert_eq!(copy.capacity(), 3);
+        assert_eq!(copy.iter().collect::<Vec<u32>>(), vec![10, 2, 3]);
+        assert_eq!(list.capacity(), 8);
+        assert_eq!(list.iter().collect::<Vec<u32>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn formats_live_items_only() {
+        let mut list = DynamicArray::<String>::with_capacity(8);
+
+        list.add(String::from("foo"));
+        list.add(String::from("bar"));
+
+        assert_eq!(format!("{:?}", list), "[\"foo\", \"bar\"]");
+        assert_eq!(format!("{:?}", DynamicArray::<u32>::new()), "[]");
+    }
+
+    #[test]
+    fn binary_searches_a_sorted_array() {
+        let list = DynamicArray::from(vec![1, 3, 5, 7, 9]);
+
+        assert_eq!(list.binary_search(&1), Ok(0));
+        assert_eq!(list.binary_search(&7), Ok(3));
+        assert_eq!(list.binary_search(&9), Ok(4));
+        assert_eq!(list.binary_search(&0), Err(0));
+        assert_eq!(list.binary_search(&4), Err(2));
+        assert_eq!(list.binary_search(&10), Err(5));
+        assert_eq!(DynamicArray::<u32>::new().binary_search(&1), Err(0));
+    }
+
+    #[test]
+    fn sorts_the_array() {
+        let mut list = DynamicArray::from(vec![5, 2, 9, 1, 7, 3]);
+
+        list.add(4);
+        list.sort();
+
+        assert_eq!(list.item_at(0), Some(1));
+        assert_eq!(list.item_at(3), Some(4));
+        assert_eq!(list.item_at(6), Some(9));
+        assert_eq!(list.iter().collect::<Vec<u32>>(), vec![1, 2, 3, 4, 5, 7, 9]);
+    }
+
+    #[test]
+    fn resizes_the_array() {
+        let mut list = DynamicArray::from(vec![1, 2]);
+
+        list.resize(5, 0);
+
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.item_at(1), Some(2));
+        assert_eq!(list.item_at(2), Some(0));
+        assert_eq!(list.item_at(4), Some(0));
+        assert_eq!(list.item_at(5), None);
+
+        list.resize(1, 0);
+
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.item_at(0), Some(1));
+        assert_eq!(list.item_at(1), None);
+    }
+
+    #[test]
+    fn grows_from_the_default_capacity() {
+        let mut list = DynamicArray::<u32>::new();
+        let mut capacities = Vec::new();
+
+        for item in 0..14 {
+            list.add(item);
+            capacities.push(list.capacity());
+        }
+
+        assert_eq!(
+            capacities,
+            vec![4, 4, 4, 4, 6, 6, 9, 9, 9, 13, 13, 13, 13, 19]
+        );
+    }
+
+    #[test]
+    fn mutates_an_item_through_reference() {
+        let mut list = DynamicArray::from(vec![1, 2, 3]);
+
+        if let Some(item) = list.get_mut(1) {
+            *item = 20;
+        }
+
+        assert_eq!(list.item_at(1), Some(20));
+        assert_eq!(list.get_mut(3), None);
+    }
+
+    #[test]
+    fn gets_first_and_last_items() {
+        let list = DynamicArray::from(vec![1, 2, 3]);
+        let empty = DynamicArray::<u32>::new();
+
+        assert_eq!(list.first(), Some(1));
+        assert_eq!(list.last(), Some(3));
+        assert_eq!(empty.first(), None);
+        assert_eq!(empty.last(), None);
+    }
+
+    #[test]
+    fn fills_the_array() {
+        let mut list = DynamicArray::from(vec![1, 2, 3]);
+
+        list.reserve(5);
+        list.fill(7);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.item_at(0), Some(7));
+        assert_eq!(list.item_at(1), Some(7));
+        assert_eq!(list.item_at(2), Some(7));
+        assert_eq!(list.item_at(3), None);
+        assert!(list.buffer[3..].iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn drains_a_range_of_items() {
+        let mut list: DynamicArray<u32> = (1..=6).collect();
+
+        assert_eq!(list.drain(1..4), vec![2, 3, 4]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().collect::<Vec<u32>>(), vec![1, 5, 6]);
+        assert!(list.buffer[3..].iter().all(Option::is_none));
+
+        assert_eq!(list.drain(0..3), vec![1, 5, 6]);
+        assert!(list.is_empty());
+        assert_eq!(list.drain(0..0), Vec::<u32>::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_when_draining_out_of_range() {
+        let mut list = DynamicArray::from(vec![1, 2, 3]);
+
+        list.drain(1..4);
+    }
+
+    #[test]
+    fn splits_the_array() {
+        let mut list: DynamicArray<u32> = (1..=5).collect();
+        let tail = list.split_off(2);
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(tail.len(), 3);
+        assert_eq!(list.iter().collect::<Vec<u32>>(), vec![1, 2]);
+        assert_eq!(tail.iter().collect::<Vec<u32>>(), vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn splits_the_array_at_boundaries() {
+        let mut list: DynamicArray<u32> = (1..=3).collect();
+        let empty = list.split_off(3);
+
+        assert_eq!(list.len(), 3);
+        assert!(empty.is_empty());
+
+        let all = list.split_off(0);
+
+        assert!(list.is_empty());
+        assert_eq!(all.iter().collect::<Vec<u32>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn reads_items_as_slice() {
+        let mut list = DynamicArray::<u32>::with_capacity(8);
+
+        list.add(1);
+        list.add(2);
+        list.add(3);
+
+        assert_eq!(list.as_slice(), &[Some(1), Some(2), Some(3)]);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        assert_eq!(DynamicArray::<u32>::new().to_vec(), Vec::<u32>::new());
+    }
+
+    #[test]
+    fn compares_live_items_only() {
+        let mut list = DynamicArray::<u32>::with_capacity(8);
+        let other = DynamicArray::from(vec![1, 2, 3]);
+
+        list.add(1);
+        list.add(2);
+        list.add(3);
+
+        assert_ne!(list.capacity(), other.capacity());
+        assert_eq!(list, other);
+
+        list.pop();
+
+        assert_ne!(list, other);
+        assert_ne!(list, DynamicArray::from(vec![1, 3]));
+    }
+
+    #[test]
+    fn rotates_the_array() {
+        let mut list: DynamicArray<u32> = (1..=5).collect();
+
+        list.reserve(3);
+        list.rotate_left(2);
+
+        assert_eq!(list.to_vec(), vec![3, 4, 5, 1, 2]);
+
+        list.rotate_right(2);
+
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 4, 5]);
+
+        list.rotate_right(2);
+
+        assert_eq!(list.to_vec(), vec![4, 5, 1, 2, 3]);
+        assert!(list.buffer[5..].iter().all(Option::is_none));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_when_rotating_out_of_range() {
+        let mut list: DynamicArray<u32> = (1..=5).collect();
+
+        list.reserve(3);
+        list.rotate_left(6);
+    }
+
+    #[test]
+    fn removes_consecutive_duplicates() {
+        let mut list = DynamicArray::from(vec![1, 1, 2, 3, 3, 1]);
+
+        list.dedup();
+
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 1]);
+        assert!(list.buffer[4..].iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn sets_items_in_range() {
+        let mut list = DynamicArray::from(vec![1, 2, 3]);
+
+        assert_eq!(list.set(1, 20), Ok(()));
+        assert!(list.set(3, 4).is_err());
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.to_vec(), vec![1, 20, 3]);
+    }
+
+    #[test]
+    fn reverses_the_array() {
+        let mut list = DynamicArray::from(vec![1, 2, 3, 4]);
+
+        list.reserve(2);
+
+        let capacity = list.capacity();
+
+        list.reverse();
+
+        assert_eq!(list.to_vec(), vec![4, 3, 2, 1]);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.capacity(), capacity);
+
+        let mut odd = DynamicArray::from(vec![1, 2, 3]);
+
+        odd.reverse();
+
+        assert_eq!(odd.to_vec(), vec![3, 2, 1]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn converts_the_array_into_a_balanced_bst() {
+        use rand::rngs::StdRng;
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+
+        let mut items: Vec<u32> = (0..100).collect();
+
+        items.shuffle(&mut StdRng::seed_from_u64(7));
+
+        let bst = DynamicArray::from(items).to_balanced_bst();
+
+        // 100 items fit in a tree of height ceil(log2(100 + 1))
+        assert_eq!(bst.height(), 7);
+        assert_eq!(
+            bst.in_order().into_iter().copied().collect::<Vec<u32>>(),
+            (0..100).collect::<Vec<u32>>()
+        );
+    }
+
+    #[test]
+    fn creates_an_empty_array_by_default() {
+        let list: DynamicArray<u32> = Default::default();
+
+        assert!(list.is_empty());
+        assert_eq!(list.capacity(), 0);
+    }
+
+    #[test]
+    fn iterates_over_references_to_the_items() {
+        let mut list = DynamicArray::<i32>::with_capacity(8);
+
+        list.add(1);
+        list.add(2);
+        list.add(3);
+
+        let mut sum = 0;
+
+        for item in &list {
+            sum += *item;
+        }
+
+        assert_eq!(sum, 6);
+        assert_eq!((&list).into_iter().count(), 3);
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn checks_whether_the_array_is_sorted() {
+        assert!(DynamicArray::<u32>::new().is_sorted());
+        assert!(DynamicArray::from(vec![7]).is_sorted());
+        assert!(DynamicArray::from(vec![1, 2, 2, 3]).is_sorted());
+        assert!(!DynamicArray::from(vec![1, 3, 2]).is_sorted());
+
+        let mut list = DynamicArray::with_capacity(8);
+
+        list.add(2);
+        list.add(1);
+        list.pop();
+
+        assert!(list.is_sorted());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn sorts_randomized_arrays() {
+        use rand::rngs::StdRng;
+        use rand::{Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for _ in 0..50 {
+            let size = rng.gen_range(0..100);
+            let mut list = DynamicArray::new();
+
+            for _ in 0..size {
+                list.add(rng.gen_range(-50..50));
+            }
+
+            let mut expect = list.to_vec();
+
+            list.sort();
+            expect.sort_unstable();
+
+            assert!(list.is_sorted());
+            assert_eq!(list.to_vec(), expect);
+        }
+    }
+
+    #[test]
+    fn swaps_items_by_index() {
+        let mut list = DynamicArray::from(vec![1, 2, 3, 4]);
+
+        list.swap(0, 3);
+        list.swap(1, 1);
+
+        assert_eq!(list.to_vec(), vec![4, 2, 3, 1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_swapping_an_item_out_of_range() {
+        let mut list = DynamicArray::with_capacity(4);
+
+        list.add(1);
+        list.add(2);
+        list.swap(0, 2);
+    }
+
+    #[test]
+    fn swaps_items_by_value() {
+        let mut list = DynamicArray::from(vec!["a", "b", "c", "b"]);
+
+        assert!(list.swap_values(&"b", &"c"));
+        assert_eq!(list.to_vec(), vec!["a", "c", "b", "b"]);
+        assert!(!list.swap_values(&"a", &"z"));
+        assert_eq!(list.to_vec(), vec!["a", "c", "b", "b"]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hashes_equal_arrays_equally() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashSet;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of(list: &DynamicArray<u32>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+
+            list.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let mut list = DynamicArray::with_capacity(10);
+
+        list.add(1);
+        list.add(2);
+        list.add(3);
+
+        let other = DynamicArray::from(vec![1, 2, 3]);
+
+        assert_eq!(list, other);
+        assert_eq!(hash_of(&list), hash_of(&other));
+        assert_ne!(hash_of(&list), hash_of(&DynamicArray::from(vec![3, 2, 1])));
+
+        let mut set = HashSet::new();
+
+        set.insert(list);
+
+        assert!(set.contains(&other));
+    }
+
+    #[test]
+    fn iterates_over_chunks_of_items() {
+        let list: DynamicArray<u32> = (1..=7).collect();
+
+        assert_eq!(
+            list.chunks(3).collect::<Vec<Vec<u32>>>(),
+            vec![vec![1, 2, 3], vec![4, 5, 6], vec![7]]
+        );
+        assert_eq!(list.chunks(10).count(), 1);
+        assert_eq!(DynamicArray::<u32>::with_capacity(4).chunks(3).count(), 0);
+    }
+
+    #[test]
+    fn iterates_over_windows_of_items() {
+        let list: DynamicArray<u32> = (1..=4).collect();
+
+        assert_eq!(
+            list.windows(2).collect::<Vec<Vec<u32>>>(),
+            vec![vec![1, 2], vec![2, 3], vec![3, 4]]
+        );
+        assert_eq!(list.windows(5).count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_chunking_by_zero_items() {
+        let list: DynamicArray<u32> = (1..=4).collect();
+
+        let _ = list.chunks(0);
+    }
+
+    #[test]
+    fn partitions_the_items_of_the_array() {
+        let list: DynamicArray<u32> = (1..=6).collect();
+        let (even, odd) = list.partition(|item| item % 2 == 0);
+
+        assert_eq!(even.to_vec(), vec![2, 4, 6]);
+        assert_eq!(odd.to_vec(), vec![1, 3, 5]);
+
+        let (all, none) = DynamicArray::from(vec![1, 2]).partition(|_| true);
+
+        assert_eq!(all.to_vec(), vec![1, 2]);
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn zips_two_arrays_up_to_the_shortest() {
+        let numbers: DynamicArray<u32> = (1..=3).collect();
+        let letters = DynamicArray::from(vec!["a", "b"]);
+
+        assert_eq!(numbers.zip(&letters).to_vec(), vec![(1, "a"), (2, "b")]);
+        assert_eq!(letters.zip(&numbers).to_vec(), vec![("a", 1), ("b", 2)]);
+        assert!(numbers.zip(&DynamicArray::<u32>::new()).is_empty());
+    }
+
+    #[test]
+    fn flattens_an_array_of_arrays() {
+        let nested = DynamicArray::from(vec![
+            DynamicArray::from(vec![1, 2]),
+            DynamicArray::from(vec![3]),
+            DynamicArray::new(),
+            DynamicArray::from(vec![4, 5]),
+        ]);
+
+        assert_eq!(nested.flatten().to_vec(), vec![1, 2, 3, 4, 5]);
+        assert!(DynamicArray::<DynamicArray<u32>>::new()
+            .flatten()
+            .is_empty());
     }
 }
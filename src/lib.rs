@@ -1,5 +1,102 @@
-mod binary_search_tree;
-mod doubly_linked_list;
-mod dynamic_array;
-mod singly_linked_list;
-mod skip_list;
+//! Implementation on Data Structures and Algorithms with Rust
+//!
+//! Every data structure lives on its own module, the `prelude` re-exports
+//! all of them so they can be brought into scope with a single import.
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # {
+//! use dsa_in_rust::prelude::*;
+//!
+//! let mut doubly_linked_list = DoublyLinkedList::new();
+//! doubly_linked_list.append(1);
+//!
+//! let mut singly_linked_list = SinglyLinkedList::new();
+//! singly_linked_list.append(1);
+//!
+//! let mut binary_search_tree = BinarySearchTree::new();
+//! binary_search_tree.add(1);
+//!
+//! let mut deque = Deque::new();
+//! deque.push_front(1);
+//!
+//! let mut dynamic_array = DynamicArray::new();
+//! dynamic_array.add(1);
+//!
+//! let mut queue = Queue::new();
+//! queue.enqueue(1);
+//!
+//! let mut red_black_tree = RedBlackTree::new();
+//! red_black_tree.insert(1);
+//!
+//! let mut skip_list = SkipList::new(4);
+//! skip_list.insert(1, "one");
+//!
+//! let mut stack = Stack::new();
+//! stack.push(1);
+//!
+//! assert_eq!(doubly_linked_list.len(), 1);
+//! assert_eq!(singly_linked_list.len(), 1);
+//! assert_eq!(binary_search_tree.find(1), Some(1));
+//! assert_eq!(deque.len(), 1);
+//! assert_eq!(dynamic_array.len(), 1);
+//! assert_eq!(queue.len(), 1);
+//! assert_eq!(red_black_tree.len(), 1);
+//! assert_eq!(skip_list.len(), 1);
+//! assert_eq!(stack.len(), 1);
+//! # }
+//! ```
+//!
+//! The `std` feature is enabled by default, without it the crate is
+//! `no_std` and only the `DynamicArray` is available.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod binary_search_tree;
+#[cfg(feature = "std")]
+pub mod bst_map;
+pub mod comparator;
+#[cfg(feature = "std")]
+pub mod deque;
+#[cfg(feature = "std")]
+pub mod doubly_linked_list;
+pub mod dynamic_array;
+#[cfg(feature = "std")]
+pub mod queue;
+#[cfg(feature = "std")]
+pub mod red_black_tree;
+#[cfg(feature = "std")]
+pub mod singly_linked_list;
+#[cfg(feature = "std")]
+pub mod skip_list;
+#[cfg(feature = "std")]
+pub mod stack;
+pub mod traits;
+
+/// Re-exports every data structure in the crate along with the comparators
+/// used to order the trees and the shared traits
+pub mod prelude {
+    #[cfg(feature = "std")]
+    pub use crate::binary_search_tree::BinarySearchTree;
+    #[cfg(feature = "std")]
+    pub use crate::bst_map::BstMap;
+    pub use crate::comparator::{Comparator, NaturalOrder, Reverse};
+    #[cfg(feature = "std")]
+    pub use crate::deque::Deque;
+    #[cfg(feature = "std")]
+    pub use crate::doubly_linked_list::DoublyLinkedList;
+    pub use crate::dynamic_array::DynamicArray;
+    #[cfg(feature = "std")]
+    pub use crate::queue::Queue;
+    #[cfg(feature = "std")]
+    pub use crate::red_black_tree::RedBlackTree;
+    #[cfg(feature = "std")]
+    pub use crate::singly_linked_list::SinglyLinkedList;
+    #[cfg(feature = "std")]
+    pub use crate::skip_list::SkipList;
+    #[cfg(feature = "std")]
+    pub use crate::stack::Stack;
+    pub use crate::traits::Collection;
+}
@@ -0,0 +1,120 @@
+//! Queue
+//!
+//! A FIFO (First In, First Out) collection built on top of a
+//! `DoublyLinkedList`, values are enqueued at the `tail` of the list and
+//! dequeued from its `head` so both operations run in constant time
+use std::fmt::Debug;
+
+use crate::doubly_linked_list::DoublyLinkedList;
+use crate::traits::Collection;
+
+/// A FIFO collection of values of type `T`
+#[derive(Debug)]
+pub struct Queue<T: Clone + Debug> {
+    list: DoublyLinkedList<T>,
+}
+
+impl<T> Queue<T>
+where
+    T: Clone + Debug,
+{
+    /// Creates a new empty `Queue`
+    pub fn new() -> Self {
+        Queue {
+            list: DoublyLinkedList::new(),
+        }
+    }
+
+    /// Retrieves the count of values in the `Queue`
+    pub fn len(&self) -> usize {
+        self.list.len() as usize
+    }
+
+    /// Returns `true` if the `Queue` holds no values
+    pub fn is_empty(&self) -> bool {
+        self.list.len() == 0
+    }
+
+    /// Adds a value to the back of the `Queue`
+    pub fn enqueue(&mut self, value: T) {
+        self.list.append(value);
+    }
+
+    /// Removes the value at the front of the `Queue` and returns it
+    pub fn dequeue(&mut self) -> Option<T> {
+        self.list.pop()
+    }
+
+    /// Retrieves a copy of the value at the front of the `Queue` without
+    /// removing it
+    pub fn front(&self) -> Option<T> {
+        self.list.front()
+    }
+}
+
+impl<T> Default for Queue<T>
+where
+    T: Clone + Debug,
+{
+    fn default() -> Self {
+        Queue::new()
+    }
+}
+
+impl<T> Collection<T> for Queue<T>
+where
+    T: Clone + Debug + PartialEq,
+{
+    fn len(&self) -> usize {
+        self.list.len() as usize
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        self.list.contains(value)
+    }
+}
+
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn creates_an_empty_queue() {
+        let queue = Queue::<u32>::new();
+
+        assert!(queue.is_empty());
+        assert_eq!(queue.len(), 0);
+        assert_eq!(queue.front(), None);
+    }
+
+    #[test]
+    fn dequeues_values_in_fifo_order() {
+        let mut queue = Queue::<u32>::new();
+
+        queue.enqueue(1);
+        queue.enqueue(2);
+
+        assert_eq!(queue.front(), Some(1));
+        assert_eq!(queue.dequeue(), Some(1));
+
+        queue.enqueue(3);
+
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.dequeue(), Some(2));
+        assert_eq!(queue.dequeue(), Some(3));
+        assert!(queue.is_empty());
+
+        queue.enqueue(4);
+
+        assert_eq!(queue.front(), Some(4));
+        assert_eq!(queue.dequeue(), Some(4));
+        assert_eq!(queue.dequeue(), None);
+    }
+
+    #[test]
+    fn creates_an_empty_queue_by_default() {
+        let queue: Queue<u32> = Default::default();
+
+        assert!(queue.is_empty());
+    }
+}
@@ -0,0 +1,1292 @@
+//! Red Black Tree
+//!
+//! A self-balancing Binary Search Tree where each `Node` is either red or
+//! black. The following invariants are restored after every insertion:
+//!
+//! - The root `Node` is black
+//! - A red `Node` never has a red child
+//! - Every path from a `Node` to its leaves goes through the same count of
+//!   black `Node`s
+//!
+//! These invariants keep the height of the tree logarithmic on the count
+//! of `Node`s.
+//!
+//! Values are ordered by a `Comparator`, which defaults to the `Ord`
+//! implementation of the values.
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::rc::{Rc, Weak};
+
+use crate::comparator::{Comparator, NaturalOrder};
+use crate::traits::Collection;
+
+/// The color of a `Node`, an empty sub-tree is considered black
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Color {
+    Red,
+    Black,
+}
+
+/// The direction a `Node` is rotated to
+#[derive(Clone, Copy)]
+enum Rotation {
+    Left,
+    Right,
+}
+
+/// A reference counted `Node`
+pub type BareTree<T> = Rc<RefCell<Node<T>>>;
+
+/// A sub-tree from this Red Black Tree
+pub type Tree<T> = Option<BareTree<T>>;
+
+/// A `Link` to the parent `Node` using a `Weak` `Rc`
+pub type ParentLink<T> = Option<Weak<RefCell<Node<T>>>>;
+
+/// A node containing a value of type `T`, its `Color`, the `Link` to its
+/// parent and its childrens.
+#[derive(Debug)]
+pub struct Node<T: Ord + Debug + Clone> {
+    color: Color,
+    value: T,
+    parent: ParentLink<T>,
+    left: Tree<T>,
+    right: Tree<T>,
+}
+
+impl<T: Ord + Debug + Clone> Node<T> {
+    /// Creates a new red `Node` with the provided value
+    pub fn new(value: T) -> BareTree<T> {
+        Rc::new(RefCell::new(Node {
+            color: Color::Red,
+            value,
+            parent: None,
+            left: None,
+            right: None,
+        }))
+    }
+
+    pub fn peek(&self) -> &T {
+        &self.value
+    }
+}
+
+/// A self-balancing Binary Search Tree which colors its `Node`s either red
+/// or black to keep the tree balanced, where the order is given by the
+/// `comparator`
+#[derive(Debug)]
+pub struct RedBlackTree<T: Ord + Debug + Clone, C = NaturalOrder> {
+    root: Tree<T>,
+    length: usize,
+    comparator: C,
+}
+
+impl<T: Ord + Debug + Clone> RedBlackTree<T> {
+    pub fn new() -> Self {
+        RedBlackTree::with_comparator(NaturalOrder)
+    }
+}
+
+impl<T: Ord + Debug + Clone, C: Comparator<T>> RedBlackTree<T, C> {
+    /// Creates a new `RedBlackTree` which orders its values using the
+    /// provided `comparator`
+    pub fn with_comparator(comparator: C) -> Self {
+        RedBlackTree {
+            root: None,
+            length: 0,
+            comparator,
+        }
+    }
+
+    /// Retrieves the count of values in the tree
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the tree holds no values
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Retrieves the lowest value of the tree according to its `comparator`
+    pub fn min(&self) -> Option<T> {
+        self.root
+            .clone()
+            .map(|root| leftmost(root).borrow().value.clone())
+    }
+
+    /// Retrieves the greatest value of the tree according to its
+    /// `comparator`
+    pub fn max(&self) -> Option<T> {
+        self.root
+            .clone()
+            .map(|root| rightmost(root).borrow().value.clone())
+    }
+
+    /// Adds a `value` to the `RedBlackTree<T>` rebalancing the tree if
+    /// required
+    pub fn insert(&mut self, value: T) {
+        let mut parent: Tree<T> = None;
+        let mut current = self.root.clone();
+
+        while let Some(node) = current {
+            current = if self.comparator.compare(&value, &node.borrow().value) != Ordering::Greater
+            {
+                node.borrow().left.clone()
+            } else {
+                node.borrow().right.clone()
+            };
+            parent = Some(node);
+        }
+
+        let node = Node::new(value);
+
+        match parent {
+            Some(parent) => {
+                node.borrow_mut().parent = Some(Rc::downgrade(&parent));
+
+                let mut parent = parent.borrow_mut();
+
+                if self.comparator.compare(&node.borrow().value, &parent.value) != Ordering::Greater
+                {
+                    parent.left = Some(Rc::clone(&node));
+                } else {
+                    parent.right = Some(Rc::clone(&node));
+                }
+            }
+            None => self.root = Some(Rc::clone(&node)),
+        }
+
+        self.length += 1;
+        self.fix_insert(node);
+    }
+
+    /// Adds every value from `values` to the `RedBlackTree<T>`
+    pub fn insert_all(&mut self, values: impl IntoIterator<Item = T>) {
+        for value in values {
+            self.insert(value);
+        }
+    }
+
+    /// Removes a `value` from the `RedBlackTree<T>` rebalancing the tree if
+    /// required.
+    ///
+    /// Returns `true` if the `value` were found and removed
+    pub fn remove(&mut self, value: &T) -> bool {
+        let node = match self.find_node(value) {
+            Some(node) => node,
+            None => return false,
+        };
+
+        let left = node.borrow().left.clone();
+        let right = node.borrow().right.clone();
+        let mut removed_color = node.borrow().color;
+
+        // the `Node` taking the place of the removed `Node` and its parent,
+        // the parent is tracked on its own as the `Node` could be empty
+        let replacement: Tree<T>;
+        let replacement_parent: Tree<T>;
+
+        match (left, right) {
+            (None, right) => {
+                replacement = right.clone();
+                replacement_parent = parent_of(&node);
+                self.transplant(&node, right);
+            }
+            (left, None) => {
+                replacement = left.clone();
+                replacement_parent = parent_of(&node);
+                self.transplant(&node, left);
+            }
+            (Some(left), Some(right)) => {
+                let successor = leftmost(right);
+
+                removed_color = successor.borrow().color;
+                replacement = successor.borrow().right.clone();
+
+                let successor_parent = parent_of(&successor).expect("A successor has a parent");
+
+                if Rc::ptr_eq(&successor_parent, &node) {
+                    replacement_parent = Some(Rc::clone(&successor));
+                } else {
+                    replacement_parent = Some(successor_parent);
+                    self.transplant(&successor, replacement.clone());
+
+                    let right = node.borrow_mut().right.take();
+
+                    if let Some(ref right) = right {
+                        right.borrow_mut().parent = Some(Rc::downgrade(&successor));
+                    }
+
+                    successor.borrow_mut().right = right;
+                }
+
+                self.transplant(&node, Some(Rc::clone(&successor)));
+                left.borrow_mut().parent = Some(Rc::downgrade(&successor));
+
+                let mut successor = successor.borrow_mut();
+
+                successor.left = Some(left);
+                successor.color = node.borrow().color;
+            }
+        }
+
+        self.length -= 1;
+
+        if removed_color == Color::Black {
+            self.fix_remove(replacement, replacement_parent);
+        }
+
+        true
+    }
+
+    /// Searches the tree for the provided value
+    pub fn find(&self, value: &T) -> Option<T> {
+        self.find_node(value)
+            .map(|node| node.borrow().value.clone())
+    }
+
+    /// Returns `true` if the tree holds a value equal to `value`
+    pub fn contains(&self, value: &T) -> bool {
+        self.find_node(value).is_some()
+    }
+
+    /// Retrieves the `Color` of the `Node` holding `value`
+    pub fn color_of(&self, value: &T) -> Option<Color> {
+        self.find_node(value).map(|node| node.borrow().color)
+    }
+
+    /// Walks the tree looking for the `Node` holding a value equal to
+    /// `value`
+    fn find_node(&self, value: &T) -> Tree<T> {
+        let mut current = self.root.clone();
+
+        while let Some(node) = current {
+            current = match self.comparator.compare(&node.borrow().value, value) {
+                Ordering::Less => node.borrow().right.clone(),
+                Ordering::Equal => return Some(Rc::clone(&node)),
+                Ordering::Greater => node.borrow().left.clone(),
+            };
+        }
+
+        None
+    }
+
+    /// Retrieves the value next to `value` in the order of the tree, which
+    /// is the lowest value of the right sub-tree of its `Node` or otherwise
+    /// the first ancestor reached from a left sub-tree.
+    ///
+    /// Returns `None` if `value` is not in the tree or is the greatest value
+    pub fn successor(&self, value: &T) -> Option<T> {
+        let node = self.find_node(value)?;
+        let right = node.borrow().right.clone();
+
+        if let Some(right) = right {
+            return Some(leftmost(right).borrow().value.clone());
+        }
+
+        let mut node = node;
+
+        while let Some(parent) = parent_of(&node) {
+            if is_left_child(&node) {
+                return Some(parent.borrow().value.clone());
+            }
+
+            node = parent;
+        }
+
+        None
+    }
+
+    /// Retrieves the value previous to `value` in the order of the tree,
+    /// which is the greatest value of the left sub-tree of its `Node` or
+    /// otherwise the first ancestor reached from a right sub-tree.
+    ///
+    /// Returns `None` if `value` is not in the tree or is the lowest value
+    pub fn predecessor(&self, value: &T) -> Option<T> {
+        let node = self.find_node(value)?;
+        let left = node.borrow().left.clone();
+
+        if let Some(left) = left {
+            return Some(rightmost(left).borrow().value.clone());
+        }
+
+        let mut node = node;
+
+        while let Some(parent) = parent_of(&node) {
+            if !is_left_child(&node) {
+                return Some(parent.borrow().value.clone());
+            }
+
+            node = parent;
+        }
+
+        None
+    }
+
+    /// Creates an iterator over the values of the tree in ascending order
+    pub fn iter(&self) -> RbIter<T> {
+        RbIter::new(self.root.clone())
+    }
+
+    /// Copies the values of the tree into a `Vec` in ascending order
+    pub fn to_sorted_vec(&self) -> Vec<T> {
+        self.iter().collect()
+    }
+
+    /// Checks whether the red black invariants holds for this tree:
+    ///
+    /// - The root `Node` is black
+    /// - A red `Node` never has a red child
+    /// - Every path from the root to its leaves goes through the same count
+    ///   of black `Node`s
+    pub fn is_valid_red_black(&self) -> bool {
+        !is_red(&self.root) && checked_black_height(&self.root).is_some()
+    }
+
+    /// Retrieves the count of black `Node`s on the path from the root to its
+    /// leaves, which is the same for every path on a valid tree
+    pub fn black_height(&self) -> usize {
+        let mut height = 0;
+        let mut current = self.root.clone();
+
+        while let Some(node) = current {
+            if node.borrow().color == Color::Black {
+                height += 1;
+            }
+
+            current = node.borrow().left.clone();
+        }
+
+        height
+    }
+
+    /// Restores the red black invariants after inserting `node`.
+    ///
+    /// While the parent of `node` is red, either the uncle is red and the
+    /// colors are pushed down from the grandparent, or the uncle is black and
+    /// the grandparent is rotated to balance the sub-tree
+    fn fix_insert(&mut self, inserted: BareTree<T>) {
+        let mut node = inserted;
+
+        while let Some(parent) = parent_of(&node) {
+            if parent.borrow().color == Color::Black {
+                break;
+            }
+
+            let grandparent = parent_of(&parent).expect("A red node is never the root");
+            let parent_is_left = is_left_child(&parent);
+            let uncle = if parent_is_left {
+                grandparent.borrow().right.clone()
+            } else {
+                grandparent.borrow().left.clone()
+            };
+
+            if is_red(&uncle) {
+                parent.borrow_mut().color = Color::Black;
+                grandparent.borrow_mut().color = Color::Red;
+
+                if let Some(uncle) = uncle {
+                    uncle.borrow_mut().color = Color::Black;
+                }
+
+                node = grandparent;
+                continue;
+            }
+
+            if parent_is_left {
+                if !is_left_child(&node) {
+                    node = parent;
+                    self.rotate(Rc::clone(&node), Rotation::Left);
+                }
+
+                let parent = parent_of(&node).expect("A rotated node has a parent");
+
+                parent.borrow_mut().color = Color::Black;
+                grandparent.borrow_mut().color = Color::Red;
+                self.rotate(grandparent, Rotation::Right);
+            } else {
+                if is_left_child(&node) {
+                    node = parent;
+                    self.rotate(Rc::clone(&node), Rotation::Right);
+                }
+
+                let parent = parent_of(&node).expect("A rotated node has a parent");
+
+                parent.borrow_mut().color = Color::Black;
+                grandparent.borrow_mut().color = Color::Red;
+                self.rotate(grandparent, Rotation::Left);
+            }
+        }
+
+        if let Some(ref root) = self.root {
+            root.borrow_mut().color = Color::Black;
+        }
+    }
+
+    /// Replaces the sub-tree rooted at `node` with the `replacement`
+    /// sub-tree on the parent of `node`
+    fn transplant(&mut self, node: &BareTree<T>, replacement: Tree<T>) {
+        let parent = parent_of(node);
+
+        if let Some(ref replacement) = replacement {
+            replacement.borrow_mut().parent = parent.as_ref().map(Rc::downgrade);
+        }
+
+        match parent {
+            Some(parent) => {
+                if is_left_child(node) {
+                    parent.borrow_mut().left = replacement;
+                } else {
+                    parent.borrow_mut().right = replacement;
+                }
+            }
+            None => self.root = replacement,
+        }
+    }
+
+    /// Restores the red black invariants after removing a black `Node`.
+    ///
+    /// The `Node` taking the place of the removed `Node` carries an "extra"
+    /// black which is either absorbed by recoloring a red `Node`, or moved
+    /// up the tree by recoloring and rotating around its sibling until it
+    /// reaches the root
+    fn fix_remove(&mut self, replacement: Tree<T>, replacement_parent: Tree<T>) {
+        let mut node = replacement;
+        let mut parent = replacement_parent;
+
+        while let Some(current_parent) = parent {
+            if is_red(&node) {
+                break;
+            }
+
+            let node_is_left = match (&current_parent.borrow().left, &node) {
+                (Some(left), Some(node)) => Rc::ptr_eq(left, node),
+                (left, _) => left.is_none(),
+            };
+            let (outer, inner) = if node_is_left {
+                (Rotation::Left, Rotation::Right)
+            } else {
+                (Rotation::Right, Rotation::Left)
+            };
+
+            let mut sibling = sibling_of(&current_parent, node_is_left);
+
+            if is_red(&Some(Rc::clone(&sibling))) {
+                sibling.borrow_mut().color = Color::Black;
+                current_parent.borrow_mut().color = Color::Red;
+                self.rotate(Rc::clone(&current_parent), outer);
+                sibling = sibling_of(&current_parent, node_is_left);
+            }
+
+            let (near, far) = if node_is_left {
+                (
+                    sibling.borrow().left.clone(),
+                    sibling.borrow().right.clone(),
+                )
+            } else {
+                (
+                    sibling.borrow().right.clone(),
+                    sibling.borrow().left.clone(),
+                )
+            };
+
+            if !is_red(&near) && !is_red(&far) {
+                sibling.borrow_mut().color = Color::Red;
+                parent = parent_of(&current_parent);
+                node = Some(current_parent);
+                continue;
+            }
+
+            if !is_red(&far) {
+                if let Some(near) = near {
+                    near.borrow_mut().color = Color::Black;
+                }
+
+                sibling.borrow_mut().color = Color::Red;
+                self.rotate(Rc::clone(&sibling), inner);
+                sibling = sibling_of(&current_parent, node_is_left);
+            }
+
+            let far = if node_is_left {
+                sibling.borrow().right.clone()
+            } else {
+                sibling.borrow().left.clone()
+            };
+
+            sibling.borrow_mut().color = current_parent.borrow().color;
+            current_parent.borrow_mut().color = Color::Black;
+
+            if let Some(far) = far {
+                far.borrow_mut().color = Color::Black;
+            }
+
+            self.rotate(current_parent, outer);
+            node = self.root.clone();
+            parent = None;
+        }
+
+        if let Some(node) = node {
+            node.borrow_mut().color = Color::Black;
+        }
+    }
+
+    /// Rotates the sub-tree rooted at `node` in the provided `direction`.
+    ///
+    /// When rotating to the left, the right child of `node` takes its place
+    /// and `node` becomes its left child. The left child of the promoted
+    /// `Node` is moved to the right of `node`. Rotating to the right is the
+    /// mirror operation.
+    fn rotate(&mut self, node: BareTree<T>, direction: Rotation) {
+        let pivot = match direction {
+            Rotation::Left => node.borrow_mut().right.take(),
+            Rotation::Right => node.borrow_mut().left.take(),
+        }
+        .expect("The rotated node must have a child in the opposite direction");
+
+        let inner = match direction {
+            Rotation::Left => pivot.borrow_mut().left.take(),
+            Rotation::Right => pivot.borrow_mut().right.take(),
+        };
+
+        if let Some(ref inner) = inner {
+            inner.borrow_mut().parent = Some(Rc::downgrade(&node));
+        }
+
+        match direction {
+            Rotation::Left => node.borrow_mut().right = inner,
+            Rotation::Right => node.borrow_mut().left = inner,
+        }
+
+        let parent = parent_of(&node);
+
+        pivot.borrow_mut().parent = parent.as_ref().map(Rc::downgrade);
+
+        match parent {
+            Some(parent) => {
+                let mut parent = parent.borrow_mut();
+                let is_left = match parent.left {
+                    Some(ref left) => Rc::ptr_eq(left, &node),
+                    None => false,
+                };
+
+                if is_left {
+                    parent.left = Some(Rc::clone(&pivot));
+                } else {
+                    parent.right = Some(Rc::clone(&pivot));
+                }
+            }
+            None => self.root = Some(Rc::clone(&pivot)),
+        }
+
+        node.borrow_mut().parent = Some(Rc::downgrade(&pivot));
+
+        match direction {
+            Rotation::Left => pivot.borrow_mut().left = Some(node),
+            Rotation::Right => pivot.borrow_mut().right = Some(node),
+        }
+    }
+}
+
+impl<T: Ord + Debug + Clone, C: Comparator<T> + Default> Default for RedBlackTree<T, C> {
+    fn default() -> Self {
+        RedBlackTree::with_comparator(C::default())
+    }
+}
+
+impl<T: Ord + Debug + Clone, C: Comparator<T>> Collection<T> for RedBlackTree<T, C> {
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        self.find_node(value).is_some()
+    }
+}
+
+/// Renders the tree sideways, one `Node` per line annotated with its color,
+/// the right sub-tree is rendered above and the left sub-tree below its
+/// parent, each level being indented by four spaces:
+///
+/// ```text
+///     8(R)
+/// 5(B)
+///     3(R)
+/// ```
+impl<T: Ord + Debug + Clone + std::fmt::Display, C> std::fmt::Display for RedBlackTree<T, C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_sideways(&self.root, 0, f)
+    }
+}
+
+impl<T: Ord + Debug + Clone> std::iter::FromIterator<T> for RedBlackTree<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = RedBlackTree::new();
+
+        tree.insert_all(iter);
+        tree
+    }
+}
+
+/// An in-order iterator over the values of a `RedBlackTree`.
+///
+/// The iterator holds a stack with the `Node`s yet to be visited, where the
+/// `Node` at the top of the stack holds the next value
+pub struct RbIter<T: Ord + Debug + Clone> {
+    stack: Vec<BareTree<T>>,
+}
+
+impl<T: Ord + Debug + Clone> RbIter<T> {
+    fn new(root: Tree<T>) -> Self {
+        let mut iter = RbIter { stack: Vec::new() };
+
+        iter.push_left_branch(root);
+        iter
+    }
+
+    /// Pushes the root of `tree` and all of its left descendants to the
+    /// stack
+    fn push_left_branch(&mut self, tree: Tree<T>) {
+        let mut current = tree;
+
+        while let Some(node) = current {
+            current = node.borrow().left.clone();
+            self.stack.push(node);
+        }
+    }
+}
+
+impl<T: Ord + Debug + Clone> Iterator for RbIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        let value = node.borrow().value.clone();
+        let right = node.borrow().right.clone();
+
+        self.push_left_branch(right);
+
+        Some(value)
+    }
+}
+
+/// Retrieves the count of black `Node`s on every path from the root of the
+/// provided `Tree` to its leaves, returns `None` if paths have different
+/// counts or a red `Node` has a red child
+fn checked_black_height<T: Ord + Debug + Clone>(tree: &Tree<T>) -> Option<usize> {
+    match tree {
+        Some(node) => {
+            let node = node.borrow();
+            let is_red_node = node.color == Color::Red;
+
+            if is_red_node && (is_red(&node.left) || is_red(&node.right)) {
+                return None;
+            }
+
+            let left = checked_black_height(&node.left)?;
+            let right = checked_black_height(&node.right)?;
+
+            if left != right {
+                return None;
+            }
+
+            Some(if is_red_node { left } else { left + 1 })
+        }
+        None => Some(0),
+    }
+}
+
+/// Writes the sub-tree rooted at `tree` sideways, where `depth` is the
+/// count of ancestors of the root of the sub-tree
+fn fmt_sideways<T: Ord + Debug + Clone + std::fmt::Display>(
+    tree: &Tree<T>,
+    depth: usize,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    if let Some(node) = tree {
+        let node = node.borrow();
+        let color = match node.color {
+            Color::Red => 'R',
+            Color::Black => 'B',
+        };
+
+        fmt_sideways(&node.right, depth + 1, f)?;
+        writeln!(f, "{}{}({})", "    ".repeat(depth), node.value, color)?;
+        fmt_sideways(&node.left, depth + 1, f)?;
+    }
+
+    Ok(())
+}
+
+/// Retrieves the parent `Node` of `node` if any
+fn parent_of<T: Ord + Debug + Clone>(node: &BareTree<T>) -> Tree<T> {
+    node.borrow().parent.as_ref().and_then(Weak::upgrade)
+}
+
+/// Checks whether `node` is the left child of its parent
+fn is_left_child<T: Ord + Debug + Clone>(node: &BareTree<T>) -> bool {
+    match parent_of(node) {
+        Some(parent) => match parent.borrow().left {
+            Some(ref left) => Rc::ptr_eq(left, node),
+            None => false,
+        },
+        None => false,
+    }
+}
+
+/// Retrieves the sibling of the child on the provided side of `parent`.
+///
+/// A sibling always exists when fixing a removal, given that the removed
+/// side is short of one black `Node`
+fn sibling_of<T: Ord + Debug + Clone>(parent: &BareTree<T>, node_is_left: bool) -> BareTree<T> {
+    let parent = parent.borrow();
+    let sibling = if node_is_left {
+        parent.right.clone()
+    } else {
+        parent.left.clone()
+    };
+
+    sibling.expect("A node carrying an extra black has a sibling")
+}
+
+/// Retrieves the left-most `Node` from the sub-tree rooted at `node`, which
+/// holds the lowest value of the sub-tree
+fn leftmost<T: Ord + Debug + Clone>(node: BareTree<T>) -> BareTree<T> {
+    let mut node = node;
+
+    loop {
+        let left = node.borrow().left.clone();
+
+        match left {
+            Some(left) => node = left,
+            None => return node,
+        }
+    }
+}
+
+/// Retrieves the right-most `Node` from the sub-tree rooted at `node`,
+/// which holds the greatest value of the sub-tree
+fn rightmost<T: Ord + Debug + Clone>(node: BareTree<T>) -> BareTree<T> {
+    let mut node = node;
+
+    loop {
+        let right = node.borrow().right.clone();
+
+        match right {
+            Some(right) => node = right,
+            None => return node,
+        }
+    }
+}
+
+/// Checks whether the root of the provided `Tree` is red, empty sub-trees
+/// are black
+fn is_red<T: Ord + Debug + Clone>(tree: &Tree<T>) -> bool {
+    match tree {
+        Some(node) => node.borrow().color == Color::Red,
+        None => false,
+    }
+}
+
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(dead_code)]
+    fn collect_in_order<T: Ord + Debug + Clone>(tree: &Tree<T>, values: &mut Vec<T>) {
+        if let Some(node) = tree {
+            let node = node.borrow();
+
+            collect_in_order(&node.left, values);
+            values.push(node.value.clone());
+            collect_in_order(&node.right, values);
+        }
+    }
+
+    #[allow(dead_code)]
+    fn attach<T: Ord + Debug + Clone>(parent: &BareTree<T>, child: &BareTree<T>, left: bool) {
+        child.borrow_mut().parent = Some(Rc::downgrade(parent));
+
+        if left {
+            parent.borrow_mut().left = Some(Rc::clone(child));
+        } else {
+            parent.borrow_mut().right = Some(Rc::clone(child));
+        }
+    }
+
+    #[allow(dead_code)]
+    fn value_of<T: Ord + Debug + Clone>(tree: &Tree<T>) -> Option<T> {
+        tree.as_ref().map(|node| node.borrow().value.clone())
+    }
+
+    #[test]
+    fn creates_an_empty_red_black_tree() {
+        let tree = RedBlackTree::<u64>::new();
+
+        assert!(tree.root.is_none());
+        assert_eq!(tree.length, 0);
+    }
+
+    #[test]
+    fn inserts_values_keeping_a_black_root() {
+        let mut tree = RedBlackTree::<u64>::new();
+
+        tree.insert(5);
+
+        assert!(!is_red(&tree.root));
+
+        tree.insert(3);
+        tree.insert(7);
+
+        let root = tree.root.clone().unwrap();
+
+        assert_eq!(*root.borrow().peek(), 5);
+        assert!(!is_red(&tree.root));
+        assert!(is_red(&root.borrow().left));
+        assert!(is_red(&root.borrow().right));
+    }
+
+    #[test]
+    fn rebalances_ascending_inserts() {
+        let mut tree = RedBlackTree::<u64>::new();
+
+        for value in 1..=10 {
+            tree.insert(value);
+        }
+
+        let mut values = Vec::new();
+
+        collect_in_order(&tree.root, &mut values);
+
+        let root = tree.root.clone().unwrap();
+
+        assert_eq!(tree.length, 10);
+        assert_eq!(*root.borrow().peek(), 4);
+        assert!(!is_red(&tree.root));
+        assert!(root.borrow().parent.is_none());
+        assert_eq!(values, (1..=10).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn inserts_values_in_any_order() {
+        let mut tree = RedBlackTree::<u64>::new();
+        let inserted = [41, 38, 31, 12, 19, 8, 8, 50, 1];
+
+        for value in inserted.iter() {
+            tree.insert(*value);
+        }
+
+        let mut values = Vec::new();
+        let mut expect = inserted.to_vec();
+
+        collect_in_order(&tree.root, &mut values);
+        expect.sort();
+
+        assert!(!is_red(&tree.root));
+        assert_eq!(values, expect);
+    }
+
+    #[test]
+    fn rotates_a_sub_tree_to_the_left() {
+        let mut tree = RedBlackTree::<u64>::new();
+        let first = Node::new(1);
+        let second = Node::new(2);
+        let third = Node::new(3);
+
+        attach(&first, &second, false);
+        attach(&second, &third, false);
+        tree.root = Some(Rc::clone(&first));
+
+        tree.rotate(Rc::clone(&first), Rotation::Left);
+
+        assert_eq!(value_of(&tree.root), Some(2));
+        assert_eq!(value_of(&second.borrow().left), Some(1));
+        assert_eq!(value_of(&second.borrow().right), Some(3));
+        assert!(second.borrow().parent.is_none());
+        assert!(Rc::ptr_eq(&parent_of(&first).unwrap(), &second));
+        assert!(Rc::ptr_eq(&parent_of(&third).unwrap(), &second));
+        assert!(first.borrow().left.is_none());
+        assert!(first.borrow().right.is_none());
+    }
+
+    #[test]
+    fn rotates_a_sub_tree_to_the_right() {
+        let mut tree = RedBlackTree::<u64>::new();
+        let first = Node::new(1);
+        let second = Node::new(2);
+        let third = Node::new(3);
+
+        attach(&third, &second, true);
+        attach(&second, &first, true);
+        tree.root = Some(Rc::clone(&third));
+
+        tree.rotate(Rc::clone(&third), Rotation::Right);
+
+        assert_eq!(value_of(&tree.root), Some(2));
+        assert_eq!(value_of(&second.borrow().left), Some(1));
+        assert_eq!(value_of(&second.borrow().right), Some(3));
+        assert!(second.borrow().parent.is_none());
+        assert!(Rc::ptr_eq(&parent_of(&first).unwrap(), &second));
+        assert!(Rc::ptr_eq(&parent_of(&third).unwrap(), &second));
+        assert!(third.borrow().left.is_none());
+        assert!(third.borrow().right.is_none());
+    }
+
+    #[test]
+    fn rotates_an_inner_sub_tree() {
+        let mut tree = RedBlackTree::<u64>::new();
+        let root = Node::new(10);
+        let pivot = Node::new(5);
+        let child = Node::new(7);
+        let grandchild = Node::new(6);
+
+        attach(&root, &pivot, true);
+        attach(&pivot, &child, false);
+        attach(&child, &grandchild, true);
+        tree.root = Some(Rc::clone(&root));
+
+        tree.rotate(Rc::clone(&pivot), Rotation::Left);
+
+        assert_eq!(value_of(&tree.root), Some(10));
+        assert_eq!(value_of(&root.borrow().left), Some(7));
+        assert_eq!(value_of(&child.borrow().left), Some(5));
+        assert_eq!(value_of(&pivot.borrow().right), Some(6));
+        assert!(Rc::ptr_eq(&parent_of(&child).unwrap(), &root));
+        assert!(Rc::ptr_eq(&parent_of(&grandchild).unwrap(), &pivot));
+
+        tree.rotate(Rc::clone(&child), Rotation::Right);
+
+        assert_eq!(value_of(&root.borrow().left), Some(5));
+        assert_eq!(value_of(&pivot.borrow().right), Some(7));
+        assert_eq!(value_of(&child.borrow().left), Some(6));
+        assert!(Rc::ptr_eq(&parent_of(&pivot).unwrap(), &root));
+        assert!(Rc::ptr_eq(&parent_of(&grandchild).unwrap(), &child));
+    }
+
+    #[test]
+    fn finds_values_in_the_tree() {
+        let mut tree = RedBlackTree::<u64>::new();
+
+        for value in [5, 10, 3, 4, 8, 1].iter() {
+            tree.insert(*value);
+        }
+
+        assert_eq!(tree.find(&5), Some(5));
+        assert_eq!(tree.find(&1), Some(1));
+        assert_eq!(tree.find(&8), Some(8));
+        assert_eq!(tree.find(&7), None);
+        assert!(tree.contains(&10));
+        assert!(tree.contains(&4));
+        assert!(!tree.contains(&11));
+        assert!(!RedBlackTree::<u64>::new().contains(&1));
+    }
+
+    #[test]
+    fn keeps_red_black_invariants_on_insert() {
+        let mut tree = RedBlackTree::<u64>::new();
+
+        assert!(tree.is_valid_red_black());
+
+        for index in 0..200 {
+            tree.insert((index * 37) % 101);
+
+            assert!(tree.is_valid_red_black());
+        }
+
+        for value in 0..100 {
+            tree.insert(value);
+
+            assert!(tree.is_valid_red_black());
+        }
+    }
+
+    #[test]
+    fn detects_broken_red_black_invariants() {
+        let mut tree = RedBlackTree::<u64>::new();
+
+        tree.insert(2);
+        tree.insert(1);
+        tree.insert(3);
+
+        let root = tree.root.clone().unwrap();
+        let left = root.borrow().left.clone().unwrap();
+
+        // a red root
+        root.borrow_mut().color = Color::Red;
+
+        assert!(!tree.is_valid_red_black());
+
+        // different black heights
+        root.borrow_mut().color = Color::Black;
+        left.borrow_mut().color = Color::Black;
+
+        assert!(!tree.is_valid_red_black());
+
+        // a red node with a red child
+        tree.insert(0);
+
+        let right = root.borrow().right.clone().unwrap();
+        let child = left.borrow().left.clone().unwrap();
+
+        left.borrow_mut().color = Color::Red;
+        right.borrow_mut().color = Color::Red;
+        child.borrow_mut().color = Color::Red;
+
+        assert!(!tree.is_valid_red_black());
+    }
+
+    #[test]
+    fn removes_values_from_the_tree() {
+        let mut tree = RedBlackTree::<u64>::new();
+
+        for value in 1..=20 {
+            tree.insert(value);
+        }
+
+        let root = *tree.root.clone().unwrap().borrow().peek();
+
+        // the root, an internal node and a leaf
+        assert!(tree.remove(&root));
+        assert!(tree.is_valid_red_black());
+        assert!(tree.remove(&4));
+        assert!(tree.is_valid_red_black());
+        assert!(tree.remove(&20));
+        assert!(tree.is_valid_red_black());
+        assert!(!tree.remove(&root));
+        assert!(!tree.remove(&21));
+
+        let mut values = Vec::new();
+        let expect: Vec<u64> = (1..=19)
+            .filter(|value| *value != root && *value != 4)
+            .collect();
+
+        collect_in_order(&tree.root, &mut values);
+
+        assert_eq!(tree.length, 17);
+        assert_eq!(values, expect);
+    }
+
+    #[test]
+    fn keeps_red_black_invariants_on_remove() {
+        let mut tree = RedBlackTree::<u64>::new();
+
+        for index in 0..101 {
+            tree.insert((index * 37) % 101);
+        }
+
+        for index in 0..101 {
+            assert!(tree.remove(&((index * 53) % 101)));
+            assert!(tree.is_valid_red_black());
+            assert_eq!(tree.length, 100 - index as usize);
+        }
+
+        assert!(tree.root.is_none());
+    }
+
+    #[test]
+    fn removes_duplicated_values() {
+        let mut tree = RedBlackTree::<u64>::new();
+
+        for value in [5, 3, 5, 8, 5, 1].iter() {
+            tree.insert(*value);
+        }
+
+        assert!(tree.remove(&5));
+        assert!(tree.remove(&5));
+        assert!(tree.is_valid_red_black());
+        assert!(tree.contains(&5));
+        assert!(tree.remove(&5));
+        assert!(!tree.contains(&5));
+
+        let mut values = Vec::new();
+
+        collect_in_order(&tree.root, &mut values);
+
+        assert_eq!(values, vec![1, 3, 8]);
+    }
+
+    #[test]
+    fn iterates_values_in_order() {
+        let mut tree = RedBlackTree::<u64>::new();
+
+        for value in [8, 7, 5, 10, 3, 4, 12, 1].iter() {
+            tree.insert(*value);
+        }
+
+        let values: Vec<u64> = tree.iter().collect();
+
+        assert_eq!(values, vec![1, 3, 4, 5, 7, 8, 10, 12]);
+        assert_eq!(tree.to_sorted_vec(), values);
+        assert_eq!(RedBlackTree::<u64>::new().iter().next(), None);
+    }
+
+    #[test]
+    fn retrieves_length_and_extreme_values() {
+        let mut tree = RedBlackTree::<u64>::new();
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.len(), 0);
+        assert_eq!(tree.min(), None);
+        assert_eq!(tree.max(), None);
+
+        for value in [8, 7, 5, 10, 3, 4, 12, 1].iter() {
+            tree.insert(*value);
+        }
+
+        assert!(!tree.is_empty());
+        assert_eq!(tree.len(), 8);
+        assert_eq!(tree.min(), Some(1));
+        assert_eq!(tree.max(), Some(12));
+
+        tree.remove(&1);
+        tree.remove(&12);
+
+        assert_eq!(tree.len(), 6);
+        assert_eq!(tree.min(), Some(3));
+        assert_eq!(tree.max(), Some(10));
+    }
+
+    #[test]
+    fn retrieves_the_color_of_a_node() {
+        let mut tree = RedBlackTree::<u64>::new();
+
+        tree.insert(5);
+        tree.insert(3);
+        tree.insert(7);
+        tree.insert(1);
+
+        assert_eq!(tree.color_of(&5), Some(Color::Black));
+        assert_eq!(tree.color_of(&3), Some(Color::Black));
+        assert_eq!(tree.color_of(&7), Some(Color::Black));
+        assert_eq!(tree.color_of(&1), Some(Color::Red));
+        assert_eq!(tree.color_of(&2), None);
+    }
+
+    #[test]
+    fn retrieves_the_black_height() {
+        let mut tree = RedBlackTree::<u64>::new();
+
+        assert_eq!(tree.black_height(), 0);
+
+        tree.insert(1);
+
+        assert_eq!(tree.black_height(), 1);
+
+        // 2 and 3 are rotated under a black root, then 4 pushes the black
+        // down to both children
+        tree.insert(2);
+        tree.insert(3);
+
+        assert_eq!(tree.black_height(), 1);
+
+        tree.insert(4);
+
+        assert_eq!(tree.black_height(), 2);
+
+        for value in 5..=10 {
+            tree.insert(value);
+        }
+
+        assert_eq!(tree.black_height(), 3);
+        assert_eq!(Some(tree.black_height()), checked_black_height(&tree.root));
+    }
+
+    #[test]
+    fn collects_values_into_a_tree() {
+        use rand::rngs::StdRng;
+        use rand::seq::SliceRandom;
+        use rand::SeedableRng;
+
+        let mut values: Vec<u64> = (0..100).collect();
+
+        values.shuffle(&mut StdRng::seed_from_u64(13));
+
+        let mut tree: RedBlackTree<u64> = values.into_iter().collect();
+
+        assert_eq!(tree.len(), 100);
+        assert!(tree.is_valid_red_black());
+        assert_eq!(tree.to_sorted_vec(), (0..100).collect::<Vec<u64>>());
+
+        tree.insert_all(vec![150, 125, 100]);
+
+        assert_eq!(tree.len(), 103);
+        assert!(tree.is_valid_red_black());
+        assert_eq!(tree.max(), Some(150));
+    }
+
+    #[test]
+    fn orders_values_with_a_comparator() {
+        use crate::comparator::Reverse;
+
+        let mut tree = RedBlackTree::with_comparator(Reverse(NaturalOrder));
+
+        tree.insert_all(1..=10);
+
+        assert_eq!(tree.to_sorted_vec(), (1..=10).rev().collect::<Vec<u64>>());
+        assert_eq!(tree.min(), Some(10));
+        assert_eq!(tree.max(), Some(1));
+        assert!(tree.contains(&4));
+        assert!(tree.is_valid_red_black());
+        assert!(tree.remove(&4));
+        assert_eq!(tree.find(&4), None);
+        assert!(tree.is_valid_red_black());
+    }
+
+    #[test]
+    fn creates_an_empty_tree_by_default() {
+        let tree: RedBlackTree<u64> = Default::default();
+
+        assert!(tree.root.is_none());
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn navigates_to_the_successor_and_predecessor() {
+        let tree: RedBlackTree<u64> = vec![20, 10, 30, 5, 15, 25, 35, 12, 17]
+            .into_iter()
+            .collect();
+
+        assert_eq!(tree.successor(&10), Some(12));
+        assert_eq!(tree.successor(&17), Some(20));
+        assert_eq!(tree.successor(&20), Some(25));
+        assert_eq!(tree.predecessor(&12), Some(10));
+        assert_eq!(tree.predecessor(&25), Some(20));
+        assert_eq!(tree.predecessor(&20), Some(17));
+
+        assert_eq!(tree.predecessor(&5), None);
+        assert_eq!(tree.successor(&5), Some(10));
+        assert_eq!(tree.successor(&35), None);
+        assert_eq!(tree.predecessor(&35), Some(30));
+        assert_eq!(tree.successor(&11), None);
+    }
+
+    #[test]
+    fn walks_the_tree_through_successors() {
+        let tree: RedBlackTree<u64> = (1..=50).rev().collect();
+        let mut values = vec![tree.min().unwrap()];
+
+        while let Some(next) = tree.successor(values.last().unwrap()) {
+            values.push(next);
+        }
+
+        assert_eq!(values, (1..=50).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn renders_the_tree_with_colors() {
+        let mut tree = RedBlackTree::<u64>::new();
+
+        assert_eq!(tree.to_string(), "");
+
+        tree.insert(5);
+        tree.insert(3);
+        tree.insert(8);
+
+        assert_eq!(tree.to_string(), "    8(R)\n5(B)\n    3(R)\n");
+
+        tree.insert(9);
+
+        assert_eq!(tree.to_string(), "        9(R)\n    8(B)\n5(B)\n    3(B)\n");
+    }
+}
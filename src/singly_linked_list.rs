@@ -4,6 +4,9 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use crate::dynamic_array::DynamicArray;
+use crate::traits::Collection;
+
 /// A `Link` to the next `Node`
 pub type Link<T> = Option<Rc<RefCell<Node<T>>>>;
 
@@ -59,6 +62,11 @@ where
         self.length
     }
 
+    /// Returns `true` if the `List` holds no `Node`s
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
     /// Appends a value to the end (tail) of the `List`
     pub fn append(&mut self, value: T) {
         let node = Node::new(value);
@@ -72,7 +80,258 @@ where
         self.tail = Some(Rc::clone(&node));
     }
 
-    /// Pops a value from the start (head) of the `List`
+    /// Prepends a value to the start (head) of the `List`
+    pub fn prepend(&mut self, value: T) {
+        let node = Node::new(value);
+
+        match self.head.take() {
+            Some(current_head_node) => node.borrow_mut().next = Some(current_head_node),
+            None => self.tail = Some(Rc::clone(&node)),
+        }
+
+        self.length += 1;
+        self.head = Some(node);
+    }
+
+    /// Swaps every two adjacent `Node`s by relinking them, a list such as
+    /// `[1, 2, 3, 4]` becomes `[2, 1, 4, 3]`.
+    ///
+    /// When the list has an odd number of `Node`s the last one is kept
+    /// in place
+    pub fn swap_pairs(&mut self) {
+        let mut previous: Link<T> = None;
+        let mut current = self.head.clone();
+
+        while let Some(first) = current {
+            let second = match first.borrow().next.clone() {
+                Some(second) => second,
+                None => break,
+            };
+            let rest = second.borrow_mut().next.take();
+
+            first.borrow_mut().next = rest.clone();
+            second.borrow_mut().next = Some(Rc::clone(&first));
+
+            match previous {
+                Some(ref previous) => previous.borrow_mut().next = Some(second),
+                None => self.head = Some(second),
+            }
+
+            if rest.is_none() {
+                self.tail = Some(Rc::clone(&first));
+            }
+
+            previous = Some(first);
+            current = rest;
+        }
+    }
+
+    /// Removes the provided `Node` from the `List`.
+    ///
+    /// Given that a `Node` doesn't hold a `Link` to the previous `Node`,
+    /// the `List` is walked from the `head` looking for the predecessor of
+    /// the provided `Node`.
+    ///
+    /// Returns `true` if the `Node` were found and removed
+    pub fn remove_node(&mut self, node: &Link<T>) -> bool {
+        let node = match node {
+            Some(node) => node,
+            None => return false,
+        };
+
+        let mut previous: Link<T> = None;
+        let mut current = self.head.clone();
+
+        while let Some(candidate) = current {
+            if Rc::ptr_eq(&candidate, node) {
+                let next = node.borrow_mut().next.take();
+                let is_tail = next.is_none();
+
+                match previous {
+                    Some(ref previous) => previous.borrow_mut().next = next,
+                    None => self.head = next,
+                }
+
+                if is_tail {
+                    self.tail = previous;
+                }
+
+                self.length -= 1;
+
+                return true;
+            }
+
+            current = candidate.borrow().next.clone();
+            previous = Some(candidate);
+        }
+
+        false
+    }
+
+    /// Executes `f` on each value from the `head` to the `tail` of the `List`
+    pub fn for_each<F: FnMut(&T)>(&self, mut f: F) {
+        self.fold((), |_, value| f(value));
+    }
+
+    /// Walks the `List` from the `head` to the `tail` accumulating each value
+    /// into `init` by executing `f`
+    pub fn fold<B, F: FnMut(B, &T) -> B>(&self, init: B, mut f: F) -> B {
+        let mut accumulator = init;
+        let mut current = self.head.clone();
+
+        while let Some(node) = current {
+            let node = node.borrow();
+
+            accumulator = f(accumulator, &node.value);
+            current = node.next.clone();
+        }
+
+        accumulator
+    }
+
+    /// Mutates the value at the `head` of the `List` in place by executing
+    /// `f`.
+    ///
+    /// Returns `false` if the `List` is empty
+    pub fn update_front<F: FnOnce(&mut T)>(&mut self, f: F) -> bool {
+        self.update_at(0, f)
+    }
+
+    /// Mutates the value at the provided `index` in place by executing `f`.
+    ///
+    /// Returns `false` if the `index` is out of range
+    pub fn update_at<F: FnOnce(&mut T)>(&mut self, index: u32, f: F) -> bool {
+        match self.node_at(index) {
+            Some(node) => {
+                f(&mut node.borrow_mut().value);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Retrieves the `Node` at the provided `index` walking the `List` from
+    /// the `head`
+    fn node_at(&self, index: u32) -> Link<T> {
+        if index >= self.length {
+            return None;
+        }
+
+        let mut current = self.head.clone();
+
+        for _ in 0..index {
+            current = current.and_then(|node| node.borrow().next.clone());
+        }
+
+        current
+    }
+}
+
+impl<T> SinglyLinkedList<T>
+where
+    T: std::fmt::Debug + PartialEq,
+{
+    /// Retrieves the first `Node` holding a value equal to `value`
+    pub fn find_node(&self, value: &T) -> Link<T> {
+        let mut current = self.head.clone();
+
+        while let Some(node) = current {
+            if node.borrow().value == *value {
+                return Some(node);
+            }
+
+            current = node.borrow().next.clone();
+        }
+
+        None
+    }
+
+    /// Removes the first `Node` holding a value equal to `value`.
+    ///
+    /// Returns `true` if a `Node` were removed
+    pub fn remove_value(&mut self, value: &T) -> bool {
+        let node = self.find_node(value);
+
+        self.remove_node(&node)
+    }
+}
+
+impl<T> SinglyLinkedList<T>
+where
+    T: std::fmt::Debug + Ord,
+{
+    /// Merges two sorted lists into a single sorted list by relinking
+    /// their `Node`s, when both lists hold an equal value the one from `a`
+    /// comes first
+    pub fn merge_sorted(mut a: SinglyLinkedList<T>, mut b: SinglyLinkedList<T>) -> Self {
+        let mut merged = SinglyLinkedList::new();
+        let mut a_current = a.head.take();
+        let mut b_current = b.head.take();
+
+        a.tail.take();
+        b.tail.take();
+
+        loop {
+            let node = match (a_current.take(), b_current.take()) {
+                (Some(a_node), Some(b_node)) => {
+                    if a_node.borrow().value <= b_node.borrow().value {
+                        a_current = a_node.borrow_mut().next.take();
+                        b_current = Some(b_node);
+                        a_node
+                    } else {
+                        b_current = b_node.borrow_mut().next.take();
+                        a_current = Some(a_node);
+                        b_node
+                    }
+                }
+                (Some(a_node), None) => {
+                    a_current = a_node.borrow_mut().next.take();
+                    a_node
+                }
+                (None, Some(b_node)) => {
+                    b_current = b_node.borrow_mut().next.take();
+                    b_node
+                }
+                (None, None) => break,
+            };
+
+            match merged.tail.take() {
+                Some(tail) => tail.borrow_mut().next = Some(Rc::clone(&node)),
+                None => merged.head = Some(Rc::clone(&node)),
+            }
+
+            merged.tail = Some(node);
+        }
+
+        merged.length = a.length + b.length;
+        merged
+    }
+}
+
+impl<T> SinglyLinkedList<T>
+where
+    T: std::fmt::Debug + PartialEq + Clone,
+{
+    /// Checks whether the values of this `List` reads the same from the
+    /// `head` to the `tail` and from the `tail` to the `head`
+    pub fn is_palindrome(&self) -> bool {
+        let values = self.fold(Vec::new(), |mut values, value| {
+            values.push(value.clone());
+            values
+        });
+
+        values.iter().eq(values.iter().rev())
+    }
+}
+
+impl<T> SinglyLinkedList<T>
+where
+    T: std::fmt::Debug + Clone,
+{
+    /// Pops a value from the start (head) of the `List`.
+    ///
+    /// The value is moved out of the `Node`, unless a handle retrieved with
+    /// `find_node` still points to it, in which case the value is cloned
     pub fn pop(&mut self) -> Option<T> {
         self.head.take().map(|head| {
             if let Some(next) = head.borrow_mut().next.take() {
@@ -83,19 +342,262 @@ where
 
             self.length -= 1;
 
-            Rc::try_unwrap(head)
-                .ok()
-                .expect("Failed to retrieve node from list")
-                .into_inner()
-                .value
+            match Rc::try_unwrap(head) {
+                Ok(head) => head.into_inner().value,
+                Err(head) => head.borrow().value.clone(),
+            }
         })
     }
+
+    /// Retrieves a copy of the value at the start (head) of the `List`
+    pub fn front(&self) -> Option<T> {
+        self.head.as_ref().map(|head| head.borrow().value.clone())
+    }
+
+    /// Creates a new `List` holding copies of the first `n` values of this
+    /// `List`, or every value if `n` is greater than its length
+    pub fn take(&self, n: u32) -> SinglyLinkedList<T> {
+        let mut list = SinglyLinkedList::new();
+        let mut current = self.head.clone();
+
+        while let Some(node) = current {
+            if list.length == n {
+                break;
+            }
+
+            list.append(node.borrow().value.clone());
+            current = node.borrow().next.clone();
+        }
+
+        list
+    }
+
+    /// Creates a new `List` holding copies of the values after the first
+    /// `n` values of this `List`, which is empty if `n` is greater than its
+    /// length
+    pub fn skip(&self, n: u32) -> SinglyLinkedList<T> {
+        let mut list = SinglyLinkedList::new();
+        let mut current = self.node_at(n);
+
+        while let Some(node) = current {
+            list.append(node.borrow().value.clone());
+            current = node.borrow().next.clone();
+        }
+
+        list
+    }
+
+    /// Creates a new `List` holding copies of every value after the head of
+    /// this `List`, or `None` if this `List` is empty
+    pub fn tail(&self) -> Option<SinglyLinkedList<T>> {
+        self.head.as_ref().map(|_| self.skip(1))
+    }
+
+    /// Retrieves the value of the first `Node` shared by both `a` and `b`,
+    /// if any.
+    ///
+    /// Two lists intersect when they share a common tail, which means that
+    /// from the intersection point onwards both lists points to the very
+    /// same `Node`s
+    pub fn intersection_point(a: &SinglyLinkedList<T>, b: &SinglyLinkedList<T>) -> Option<T> {
+        let mut a_current = a.head.clone();
+        let mut b_current = b.head.clone();
+
+        // advance the longest list so both have the same count of
+        // `Node`s left to walk
+        for _ in b.length..a.length {
+            a_current = a_current.and_then(|node| node.borrow().next.clone());
+        }
+
+        for _ in a.length..b.length {
+            b_current = b_current.and_then(|node| node.borrow().next.clone());
+        }
+
+        while let (Some(a_node), Some(b_node)) = (a_current, b_current) {
+            if Rc::ptr_eq(&a_node, &b_node) {
+                return Some(a_node.borrow().value.clone());
+            }
+
+            a_current = a_node.borrow().next.clone();
+            b_current = b_node.borrow().next.clone();
+        }
+
+        None
+    }
+}
+
+impl<T> Default for SinglyLinkedList<T>
+where
+    T: std::fmt::Debug,
+{
+    fn default() -> Self {
+        SinglyLinkedList::new()
+    }
+}
+
+/// Two lists are equal when they hold equal values in the same order
+impl<T> PartialEq for SinglyLinkedList<T>
+where
+    T: std::fmt::Debug + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        if self.length != other.length {
+            return false;
+        }
+
+        let mut current = self.head.clone();
+        let mut other_current = other.head.clone();
+
+        while let (Some(node), Some(other_node)) = (current, other_current) {
+            if node.borrow().value != other_node.borrow().value {
+                return false;
+            }
+
+            current = node.borrow().next.clone();
+            other_current = other_node.borrow().next.clone();
+        }
+
+        true
+    }
+}
+
+impl<T> Eq for SinglyLinkedList<T> where T: std::fmt::Debug + Eq {}
+
+/// Hashes the length and the values of the list from `head` to `tail`, so
+/// equal lists produce equal hashes
+impl<T> std::hash::Hash for SinglyLinkedList<T>
+where
+    T: std::fmt::Debug + std::hash::Hash,
+{
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.length.hash(state);
+
+        let mut current = self.head.clone();
+
+        while let Some(node) = current {
+            node.borrow().value.hash(state);
+            current = node.borrow().next.clone();
+        }
+    }
+}
+
+impl<T> Collection<T> for SinglyLinkedList<T>
+where
+    T: std::fmt::Debug + PartialEq,
+{
+    fn len(&self) -> usize {
+        self.length as usize
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        self.find_node(value).is_some()
+    }
+}
+
+impl<T> From<SinglyLinkedList<T>> for DynamicArray<T>
+where
+    T: std::fmt::Debug + Clone,
+{
+    /// Moves the values of the list into an array keeping their order
+    fn from(mut list: SinglyLinkedList<T>) -> Self {
+        let mut array = DynamicArray::with_capacity(list.length as usize);
+
+        while let Some(value) = list.pop() {
+            array.add(value);
+        }
+
+        array
+    }
+}
+
+impl<T> From<DynamicArray<T>> for SinglyLinkedList<T>
+where
+    T: std::fmt::Debug + Clone,
+{
+    /// Moves the items of the array into a list keeping their order
+    fn from(array: DynamicArray<T>) -> Self {
+        let mut list = SinglyLinkedList::new();
+
+        for value in array {
+            list.append(value);
+        }
+
+        list
+    }
+}
+
+/// Serializes the list as the sequence of its values from `head` to `tail`
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for SinglyLinkedList<T>
+where
+    T: std::fmt::Debug + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.length as usize))?;
+        let mut current = self.head.clone();
+
+        while let Some(node) = current {
+            seq.serialize_element(&node.borrow().value)?;
+            current = node.borrow().next.clone();
+        }
+
+        seq.end()
+    }
+}
+
+/// Deserializes a list from a sequence of values appending them in order
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for SinglyLinkedList<T>
+where
+    T: std::fmt::Debug + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut list = SinglyLinkedList::new();
+
+        for value in Vec::<T>::deserialize(deserializer)? {
+            list.append(value);
+        }
+
+        Ok(list)
+    }
 }
 
 mod tests {
     #[allow(unused_imports)]
     use super::*;
 
+    #[allow(dead_code)]
+    fn drain<T: std::fmt::Debug + Clone>(list: &mut SinglyLinkedList<T>) -> Vec<T> {
+        let mut values = Vec::new();
+
+        while let Some(value) = list.pop() {
+            values.push(value);
+        }
+
+        values
+    }
+
+    /// Links the `tail` of `list` to the `Node` at `index` from `other`,
+    /// making both lists share the same tail
+    #[allow(dead_code)]
+    fn join_at<T: std::fmt::Debug>(
+        list: &mut SinglyLinkedList<T>,
+        other: &SinglyLinkedList<T>,
+        index: u32,
+    ) {
+        let node = other.node_at(index);
+
+        match list.tail.take() {
+            Some(tail) => tail.borrow_mut().next = node,
+            None => list.head = node,
+        }
+
+        list.tail = other.tail.clone();
+        list.length += other.length - index;
+    }
+
     #[test]
     fn create_an_empty_singly_linked_list() {
         let list = SinglyLinkedList::<String>::new();
@@ -164,4 +666,422 @@ mod tests {
         assert_eq!(third_element, String::from("MyLastValue"));
         assert_eq!(list.len(), 0);
     }
+
+    #[test]
+    fn swaps_pairs_on_a_list_of_even_length() {
+        let mut list = SinglyLinkedList::<u32>::new();
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+        list.append(4);
+
+        list.swap_pairs();
+
+        let tail_value = *list.tail.as_ref().unwrap().borrow().peek_value();
+
+        assert_eq!(tail_value, 3);
+        assert_eq!(list.len(), 4);
+        assert_eq!(drain(&mut list), vec![2, 1, 4, 3]);
+    }
+
+    #[test]
+    fn swaps_pairs_on_a_list_of_odd_length() {
+        let mut list = SinglyLinkedList::<u32>::new();
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        list.swap_pairs();
+
+        let tail_value = *list.tail.as_ref().unwrap().borrow().peek_value();
+
+        assert_eq!(tail_value, 3);
+        assert_eq!(list.len(), 3);
+        assert_eq!(drain(&mut list), vec![2, 1, 3]);
+    }
+
+    #[test]
+    fn finds_a_node_by_value() {
+        let mut list = SinglyLinkedList::<u32>::new();
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        let node = list.find_node(&2).unwrap();
+
+        assert_eq!(*node.borrow().peek_value(), 2);
+        assert!(list.find_node(&4).is_none());
+    }
+
+    #[test]
+    fn pops_a_node_while_a_handle_to_it_is_alive() {
+        let mut list = SinglyLinkedList::<u32>::new();
+
+        list.append(1);
+        list.append(2);
+
+        let head = list.find_node(&1).unwrap();
+
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(*head.borrow().peek_value(), 1);
+        assert_eq!(list.len(), 1);
+
+        let tail = list.find_node(&2);
+
+        assert_eq!(DynamicArray::from(list).to_vec(), vec![2]);
+        assert!(tail.is_some());
+    }
+
+    #[test]
+    fn removes_a_node_by_handle() {
+        let mut list = SinglyLinkedList::<u32>::new();
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        let middle = list.find_node(&2);
+        let last = list.find_node(&3);
+
+        assert!(list.remove_node(&middle));
+        assert!(!list.remove_node(&middle));
+        assert!(list.remove_node(&last));
+        assert!(!list.remove_node(&None));
+        assert_eq!(list.len(), 1);
+
+        drop(middle);
+        drop(last);
+
+        let tail_value = *list.tail.as_ref().unwrap().borrow().peek_value();
+
+        assert_eq!(tail_value, 1);
+        assert_eq!(drain(&mut list), vec![1]);
+    }
+
+    #[test]
+    fn folds_the_list_values() {
+        let mut list = SinglyLinkedList::<u32>::new();
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+        list.append(4);
+
+        assert_eq!(list.fold(0, |sum, value| sum + value), 10);
+        assert_eq!(
+            SinglyLinkedList::<u32>::new().fold(0, |sum, value| sum + value),
+            0
+        );
+    }
+
+    #[test]
+    fn executes_a_function_for_each_value() {
+        let mut list = SinglyLinkedList::<String>::new();
+        let mut touched_items = Vec::new();
+
+        list.append(String::from("MyValue"));
+        list.append(String::from("MySecondValue"));
+
+        list.for_each(|value| touched_items.push(value.clone()));
+
+        assert_eq!(
+            touched_items,
+            vec![String::from("MyValue"), String::from("MySecondValue")]
+        );
+    }
+
+    #[test]
+    fn checks_if_the_list_is_palindrome() {
+        let mut odd = SinglyLinkedList::<u32>::new();
+        let mut even = SinglyLinkedList::<u32>::new();
+        let mut not_palindrome = SinglyLinkedList::<u32>::new();
+
+        odd.append(1);
+        odd.append(2);
+        odd.append(1);
+
+        even.append(1);
+        even.append(2);
+        even.append(2);
+        even.append(1);
+
+        not_palindrome.append(1);
+        not_palindrome.append(2);
+        not_palindrome.append(3);
+
+        assert!(odd.is_palindrome());
+        assert!(even.is_palindrome());
+        assert!(!not_palindrome.is_palindrome());
+    }
+
+    #[test]
+    fn updates_values_in_place() {
+        let mut list = SinglyLinkedList::<u32>::new();
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+
+        assert!(list.update_front(|value| *value = 10));
+        assert!(list.update_at(1, |value| *value *= 10));
+        assert!(!list.update_at(3, |value| *value = 0));
+        assert!(!SinglyLinkedList::<u32>::new().update_front(|value| *value = 0));
+        assert_eq!(drain(&mut list), vec![10, 20, 3]);
+    }
+
+    #[test]
+    fn removes_the_first_match_of_a_value() {
+        let mut list = SinglyLinkedList::<u32>::new();
+
+        list.append(1);
+        list.append(2);
+        list.append(3);
+        list.append(2);
+        list.append(4);
+
+        assert!(list.remove_value(&2));
+        assert!(list.remove_value(&1));
+        assert!(list.remove_value(&4));
+        assert!(!list.remove_value(&5));
+        assert_eq!(list.len(), 2);
+
+        let tail_value = *list.tail.as_ref().unwrap().borrow().peek_value();
+
+        assert_eq!(tail_value, 2);
+        assert_eq!(drain(&mut list), vec![3, 2]);
+    }
+
+    #[test]
+    fn finds_the_intersection_point_of_two_lists() {
+        let mut a = SinglyLinkedList::<u32>::new();
+        let mut b = SinglyLinkedList::<u32>::new();
+
+        a.append(1);
+        a.append(2);
+        a.append(3);
+        a.append(4);
+        a.append(5);
+
+        b.append(9);
+        join_at(&mut b, &a, 3);
+
+        assert_eq!(b.len(), 3);
+        assert_eq!(SinglyLinkedList::intersection_point(&a, &b), Some(4));
+        assert_eq!(SinglyLinkedList::intersection_point(&b, &a), Some(4));
+    }
+
+    #[test]
+    fn finds_no_intersection_point_on_disjoint_lists() {
+        let mut a = SinglyLinkedList::<u32>::new();
+        let mut b = SinglyLinkedList::<u32>::new();
+
+        a.append(1);
+        a.append(2);
+        a.append(3);
+
+        b.append(2);
+        b.append(3);
+
+        assert_eq!(SinglyLinkedList::intersection_point(&a, &b), None);
+        assert_eq!(
+            SinglyLinkedList::intersection_point(&a, &SinglyLinkedList::new()),
+            None
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serializes_and_deserializes_the_list() {
+        let mut list = SinglyLinkedList::<u32>::new();
+
+        list.append(3);
+        list.append(1);
+        list.append(2);
+
+        let json = serde_json::to_string(&list).unwrap();
+
+        assert_eq!(json, "[3,1,2]");
+
+        let mut restored: SinglyLinkedList<u32> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.len(), 3);
+        assert_eq!(drain(&mut restored), vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn prepends_values_to_the_list() {
+        let mut list = SinglyLinkedList::<u32>::new();
+
+        list.prepend(2);
+        list.prepend(1);
+        list.append(3);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(*list.tail.as_ref().unwrap().borrow().peek_value(), 3);
+        assert_eq!(drain(&mut list), vec![1, 2, 3]);
+        assert_eq!(list.front(), None);
+    }
+
+    #[test]
+    fn converts_a_list_into_a_dynamic_array_and_back() {
+        let mut list = SinglyLinkedList::<u32>::new();
+
+        list.append(3);
+        list.append(1);
+        list.append(2);
+
+        let array = DynamicArray::from(list);
+
+        assert_eq!(array.len(), 3);
+        assert_eq!(array.to_vec(), vec![3, 1, 2]);
+
+        let mut list = SinglyLinkedList::from(array);
+
+        assert_eq!(list.len(), 3);
+        assert_eq!(drain(&mut list), vec![3, 1, 2]);
+    }
+
+    #[test]
+    fn converts_an_empty_list_into_a_dynamic_array() {
+        let array = DynamicArray::from(SinglyLinkedList::<u32>::new());
+
+        assert!(array.is_empty());
+        assert_eq!(SinglyLinkedList::from(array).len(), 0);
+    }
+
+    #[test]
+    fn creates_an_empty_list_by_default() {
+        let list: SinglyLinkedList<String> = Default::default();
+
+        assert!(list.head.is_none());
+        assert!(list.tail.is_none());
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn merges_two_sorted_lists() {
+        let mut a = SinglyLinkedList::<u32>::new();
+        let mut b = SinglyLinkedList::<u32>::new();
+
+        a.append(1);
+        a.append(3);
+        a.append(5);
+        b.append(2);
+        b.append(4);
+        b.append(6);
+
+        let mut merged = SinglyLinkedList::merge_sorted(a, b);
+
+        assert_eq!(merged.len(), 6);
+        assert_eq!(*merged.tail.as_ref().unwrap().borrow().peek_value(), 6);
+        assert_eq!(drain(&mut merged), vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn merges_a_sorted_list_with_an_empty_list() {
+        let mut a = SinglyLinkedList::<u32>::new();
+
+        a.append(1);
+        a.append(2);
+
+        let mut merged = SinglyLinkedList::merge_sorted(a, SinglyLinkedList::new());
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(drain(&mut merged), vec![1, 2]);
+
+        let mut b = SinglyLinkedList::<u32>::new();
+
+        b.append(3);
+
+        let mut merged = SinglyLinkedList::merge_sorted(SinglyLinkedList::new(), b);
+
+        merged.append(4);
+
+        assert_eq!(merged.len(), 2);
+        assert_eq!(drain(&mut merged), vec![3, 4]);
+
+        let merged =
+            SinglyLinkedList::<u32>::merge_sorted(SinglyLinkedList::new(), SinglyLinkedList::new());
+
+        assert!(merged.is_empty());
+        assert!(merged.head.is_none());
+        assert!(merged.tail.is_none());
+    }
+
+    #[test]
+    fn hashes_equal_lists_equally() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of(list: &SinglyLinkedList<u32>) -> u64 {
+            let mut hasher = DefaultHasher::new();
+
+            list.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let mut list = SinglyLinkedList::new();
+        let mut other = SinglyLinkedList::new();
+        let mut reversed = SinglyLinkedList::new();
+
+        for value in 1..=3 {
+            list.append(value);
+            other.append(value);
+            reversed.prepend(value);
+        }
+
+        assert!(list == other);
+        assert!(list != reversed);
+        assert_eq!(hash_of(&list), hash_of(&other));
+        assert_ne!(hash_of(&list), hash_of(&reversed));
+    }
+
+    #[test]
+    fn takes_the_first_values_into_a_new_list() {
+        let mut list = SinglyLinkedList::<u32>::new();
+
+        for value in 1..=5 {
+            list.append(value);
+        }
+
+        assert_eq!(drain(&mut list.take(2)), vec![1, 2]);
+        assert_eq!(drain(&mut list.take(0)), Vec::<u32>::new());
+        assert_eq!(drain(&mut list.take(10)), vec![1, 2, 3, 4, 5]);
+        assert_eq!(list.take(2).len(), 2);
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    fn skips_the_first_values_into_a_new_list() {
+        let mut list = SinglyLinkedList::<u32>::new();
+
+        for value in 1..=5 {
+            list.append(value);
+        }
+
+        assert_eq!(drain(&mut list.skip(2)), vec![3, 4, 5]);
+        assert_eq!(drain(&mut list.skip(0)), vec![1, 2, 3, 4, 5]);
+        assert_eq!(drain(&mut list.skip(5)), Vec::<u32>::new());
+        assert_eq!(drain(&mut list.skip(10)), Vec::<u32>::new());
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    fn copies_the_values_after_the_head_into_a_tail() {
+        let mut list = SinglyLinkedList::<u32>::new();
+
+        assert!(list.tail().is_none());
+
+        for value in 1..=3 {
+            list.append(value);
+        }
+
+        assert_eq!(drain(&mut list.tail().unwrap()), vec![2, 3]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(drain(&mut list), vec![1, 2, 3]);
+    }
 }
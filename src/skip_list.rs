@@ -1,25 +1,34 @@
 //! Skip List
 //!
 //! A List Data Structure where each node hold multiple references to
-//! other nodes of the same list.
+//! other nodes of the same list, nodes are kept sorted by their `key`.
 //!
 //! Each node is inserted using a probabilistic approach in order to
 //! have a balanced list and avoid ruining performance on "find" operations.
 //!
 //! Ideally each node should hold half the number of nodes the previous
 //! level has.
-use rand::random;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use std::cell::RefCell;
+use std::marker::PhantomData;
 use std::rc::Rc;
 
+use crate::traits::Collection;
+
+/// Highest level of a default `SkipList`, enough to keep
+/// lookups logarithmic for lists of up to 65536 `Node`s
+const DEFAULT_MAX_LEVEL: usize = 16;
+
 /// A `Link` to the next `Node`
 pub type Link<T> = Option<Rc<RefCell<Node<T>>>>;
 
-/// A `List` `Node` holding a value of type `T` and the `Link` to the
-/// next `Node` which could be `None`
+/// A `List` `Node` holding a `key`, a value of type `T` and the `Link`s to
+/// the next `Node` on each level the `Node` is present on
 #[derive(Clone, Debug)]
 pub struct Node<T: std::fmt::Debug> {
     links: Vec<Link<T>>,
+    key: usize,
     value: T,
 }
 
@@ -27,76 +36,318 @@ impl<T> Node<T>
 where
     T: std::fmt::Debug,
 {
-    /// Creates a new `Node` with the provided value
-    pub fn new(level: usize, value: T) -> Rc<RefCell<Self>> {
+    /// Creates a new `Node` present on `level` levels with the provided
+    /// `key` and value
+    pub fn new(level: usize, key: usize, value: T) -> Rc<RefCell<Self>> {
         Rc::new(RefCell::new(Node {
             links: vec![None; level],
+            key,
             value,
         }))
     }
 
+    /// Retrieves this `Node`'s key
+    pub fn peek_key(&self) -> usize {
+        self.key
+    }
+
     /// Retrieves a reference to this `Node`'s value
     pub fn peek_value(&self) -> &T {
         &self.value
     }
 }
 
+/// A collection of `Node`s sorted by `key`, where the `head` holds the
+/// `Link` to the first `Node` on each level and the `tails` holds the last
+/// `Node` on each level.
+///
+/// The `probability` is the chance of a `Node` to be present on the next
+/// level, and `rng` is the source of randomness used to pick levels
 #[derive(Debug)]
 pub struct SkipList<T: std::fmt::Debug> {
-    head: Link<T>,
+    head: Vec<Link<T>>,
     tails: Vec<Link<T>>,
-    length: u32,
+    length: usize,
     max_level: usize,
+    probability: f64,
+    rng: RefCell<StdRng>,
 }
 
 impl<T> SkipList<T>
 where
     T: std::fmt::Debug,
 {
+    /// Creates a new `SkipList` where `Node`s are present on the next
+    /// level half of the time
     pub fn new(max_level: usize) -> Self {
+        SkipList::with_rng(max_level, 0.5, StdRng::from_entropy())
+    }
+
+    /// Creates a new `SkipList` which picks levels from a random number
+    /// generator seeded with `seed`, so the same sequence of inserts
+    /// always builds the same structure.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `probability` is not in the `0.0..=1.0` range
+    pub fn with_seed(max_level: usize, probability: f64, seed: u64) -> Self {
+        SkipList::with_rng(max_level, probability, StdRng::seed_from_u64(seed))
+    }
+
+    fn with_rng(max_level: usize, probability: f64, rng: StdRng) -> Self {
+        if !(0.0..=1.0).contains(&probability) {
+            panic!(
+                "Probability (is {}) should be in the range 0.0..=1.0",
+                probability
+            );
+        }
+
         SkipList {
-            head: None,
+            head: vec![None; max_level + 1],
             tails: vec![None; max_level + 1],
             length: 0,
             max_level,
+            probability,
+            rng: RefCell::new(rng),
         }
     }
 
+    /// Retrieves the count of `Node`s in the `List`
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns `true` if the `List` holds no `Node`s
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Retrieves the highest level holding at least one `Node`, which is
+    /// never greater than `max_level`. An empty `List` is on level 0
+    pub fn current_level(&self) -> usize {
+        self.head.iter().rposition(Option::is_some).unwrap_or(0)
+    }
+
+    /// Retrieves the count of `Node`s present on each level, from the
+    /// bottom level up to `max_level`.
+    ///
+    /// Every `Node` is present on the bottom level, and each level should
+    /// hold about `probability` times the `Node`s of the level below
+    pub fn node_count_per_level(&self) -> Vec<usize> {
+        (0..self.head.len())
+            .map(|level| {
+                let mut count = 0;
+                let mut current = self.head[level].clone();
+
+                while let Some(node) = current {
+                    count += 1;
+                    current = node.borrow().links[level].clone();
+                }
+
+                count
+            })
+            .collect()
+    }
+
+    /// Inserts a value with the provided `key` into the `List`.
+    ///
+    /// The `Node` is present on a random count of levels, and it's linked
+    /// right after the last `Node` with a lower `key` on each of them.
+    ///
+    /// Keys are not required to be unique, use `upsert` to overwrite the
+    /// value of an existing `key` instead
+    pub fn insert(&mut self, key: usize, value: T) {
+        let level = 1 + self.random_level();
+        let new: Rc<RefCell<Node<T>>> = Node::new(level, key, value);
+        let predecessors = self.predecessors(key);
+
+        for (i, predecessor) in predecessors.iter().enumerate().take(level) {
+            let next = match predecessor {
+                Some(predecessor) => predecessor.borrow_mut().links[i].replace(new.clone()),
+                None => self.head[i].replace(new.clone()),
+            };
+
+            // a `Node` without next `Node` is the tail of the level
+            if next.is_none() {
+                self.tails[i] = Some(new.clone());
+            }
+
+            new.borrow_mut().links[i] = next;
+        }
+
+        self.length += 1;
+    }
+
+    /// Appends a value at the end of the `List`, using the `key` right after
+    /// the highest `key` in the `List`, or `0` if the `List` is empty
     pub fn append(&mut self, value: T) {
-        // the first node should be present on all levels
-        let level = 1 + if self.head.is_none() {
-            // the first node should use the maximum level
-            self.max_level
-        } else {
-            // if theres already a `head` node defined
-            // use a random level
-            self.get_level()
-        };
+        let key = self.max_key().map_or(0, |key| key + 1);
+
+        self.insert(key, value);
+    }
+
+    /// Inserts a value with the provided `key` into the `List` unless a
+    /// `Node` with the same `key` exists already, in which case its value is
+    /// overwritten and the previous value is returned
+    pub fn upsert(&mut self, key: usize, value: T) -> Option<T> {
+        match self.find(key) {
+            Some(node) => Some(std::mem::replace(&mut node.borrow_mut().value, value)),
+            None => {
+                self.insert(key, value);
+                None
+            }
+        }
+    }
 
-        let new: Rc<RefCell<Node<T>>> = Node::new(level, value);
+    /// Removes the `Node` with the provided `key` from the `List` returning
+    /// its value.
+    ///
+    /// The `Node` is unlinked from every level it is present on, levels left
+    /// without `Node`s are empty again.
+    ///
+    /// The value is moved out of the `Node`, so if a handle retrieved with
+    /// `find` still points to the `Node` it is unlinked but `None` is
+    /// returned, and its value stays reachable through that handle
+    pub fn remove(&mut self, key: usize) -> Option<T> {
+        let predecessors = self.predecessors(key);
+        let target = self
+            .next_of(&predecessors[0], 0)
+            .filter(|node| node.borrow().key == key)?;
+        let level = target.borrow().links.len();
 
-        // every node should now have their tails updated with the new node
-        for i in 0..level {
-            if let Some(old) = self.tails[i].take() {
-                let next = &mut old.borrow_mut().links;
+        for (i, predecessor) in predecessors.iter().enumerate().take(level) {
+            let next = target.borrow_mut().links[i].take();
 
-                next[i] = Some(new.clone());
+            if next.is_none() {
+                self.tails[i] = predecessor.clone();
             }
 
-            self.tails[i] = Some(new.clone());
+            match predecessor {
+                Some(predecessor) => predecessor.borrow_mut().links[i] = next,
+                None => self.head[i] = next,
+            }
+        }
+
+        self.length -= 1;
+
+        Rc::try_unwrap(target)
+            .ok()
+            .map(|node| node.into_inner().value)
+    }
+
+    /// Retrieves the `Node` with the provided `key`.
+    ///
+    /// The search starts on the highest level and drops down a level every
+    /// time the next `Node` has a greater or equal `key`, until the bottom
+    /// level is reached
+    pub fn find(&self, key: usize) -> Link<T> {
+        self.first_not_lower(key)
+            .filter(|node| node.borrow().key == key)
+    }
+
+    /// Retrieves the first `Node` with a `key` greater than or equal to the
+    /// provided `key`
+    fn first_not_lower(&self, key: usize) -> Link<T> {
+        self.next_of(&self.last_lower(key), 0)
+    }
+
+    /// Retrieves the last `Node` with a `key` lower than the provided `key`,
+    /// dropping down a level every time the next `Node` has a greater or
+    /// equal `key`
+    fn last_lower(&self, key: usize) -> Link<T> {
+        let mut current: Link<T> = None;
+
+        for level in (0..self.head.len()).rev() {
+            current = self.last_lower_on_level(current, level, key);
         }
 
-        if self.head.is_none() {
-            self.head = Some(new.clone());
+        current
+    }
+
+    /// Returns `true` if the `List` holds a `Node` with the provided `key`
+    pub fn contains(&self, key: usize) -> bool {
+        self.find(key).is_some()
+    }
+
+    /// Retrieves the lowest `key` in the `List`
+    pub fn min_key(&self) -> Option<usize> {
+        self.head[0].as_ref().map(|node| node.borrow().key)
+    }
+
+    /// Retrieves the highest `key` in the `List`
+    pub fn max_key(&self) -> Option<usize> {
+        self.last_node().map(|node| node.borrow().key)
+    }
+
+    /// Retrieves the last `Node` of the bottom level, which is the `Node`
+    /// with the highest `key`
+    fn last_node(&self) -> Link<T> {
+        self.tails[0].clone()
+    }
+
+    /// Creates an iterator over the `(key, value)` pairs of the `List` in
+    /// ascending `key` order
+    pub fn iter(&self) -> SkipIter<'_, T> {
+        SkipIter::new(self.head[0].clone())
+    }
+
+    /// Creates a `SkipCursor` placed before the first `Node` of the `List`
+    pub fn cursor(&self) -> SkipCursor<'_, T> {
+        SkipCursor::new(self.head[0].clone())
+    }
+
+    /// Retrieves the last `Node` with a `key` lower than the provided `key`
+    /// on each level, where `None` stands for the `head` of the level
+    fn predecessors(&self, key: usize) -> Vec<Link<T>> {
+        let mut predecessors = vec![None; self.head.len()];
+        let mut current: Link<T> = None;
+
+        for level in (0..self.head.len()).rev() {
+            current = self.last_lower_on_level(current, level, key);
+            predecessors[level] = current.clone();
         }
 
-        self.length += 1;
+        predecessors
+    }
+
+    /// Walks the provided `level` from `start` while the next `Node` has a
+    /// `key` lower than the provided `key`, returning the last `Node`
+    /// visited, where `None` stands for the `head` of the level
+    fn last_lower_on_level(&self, start: Link<T>, level: usize, key: usize) -> Link<T> {
+        let mut current = start;
+
+        loop {
+            let next = self.next_of(&current, level);
+            let advance = match next {
+                Some(ref next) => next.borrow().key < key,
+                None => false,
+            };
+
+            if !advance {
+                return current;
+            }
+
+            current = next;
+        }
+    }
+
+    /// Retrieves the `Node` next to `node` on the provided `level`, where
+    /// a `None` `node` stands for the `head` of the level
+    fn next_of(&self, node: &Link<T>, level: usize) -> Link<T> {
+        match node {
+            Some(node) => node.borrow().links[level].clone(),
+            None => self.head[level].clone(),
+        }
     }
 
-    fn get_level(&self) -> usize {
+    /// Picks the highest level a new `Node` is present on, every level
+    /// above the bottom one is reached with the list `probability` and
+    /// the level never exceeds `max_level`
+    fn random_level(&self) -> usize {
+        let mut rng = self.rng.borrow_mut();
         let mut level = 0;
 
-        while random::<bool>() && level < self.max_level {
+        while level < self.max_level && rng.gen_bool(self.probability) {
             level += 1;
         }
 
@@ -104,15 +355,232 @@ where
     }
 }
 
+impl<T> SkipList<T>
+where
+    T: std::fmt::Debug + Clone,
+{
+    /// Retrieves a copy of the value with the provided `key`
+    pub fn get(&self, key: usize) -> Option<T> {
+        self.find(key).map(|node| node.borrow().value.clone())
+    }
+
+    /// Retrieves a copy of the entry with the greatest `key` lower than or
+    /// equal to the provided `key`
+    pub fn floor(&self, key: usize) -> Option<(usize, T)> {
+        let node = match key.checked_add(1) {
+            Some(bound) => self.last_lower(bound),
+            None => self.last_node(),
+        };
+
+        node.map(|node| {
+            let node = node.borrow();
+
+            (node.key, node.value.clone())
+        })
+    }
+
+    /// Retrieves a copy of the entry with the lowest `key` greater than or
+    /// equal to the provided `key`
+    pub fn ceil(&self, key: usize) -> Option<(usize, T)> {
+        self.first_not_lower(key).map(|node| {
+            let node = node.borrow();
+
+            (node.key, node.value.clone())
+        })
+    }
+
+    /// Retrieves copies of the values with a `key` in the `low..=high` range
+    /// in ascending `key` order
+    pub fn range(&self, low: usize, high: usize) -> Vec<T> {
+        self.range_iter(low, high).map(|(_, value)| value).collect()
+    }
+
+    /// Creates an iterator over the `(key, value)` pairs with a `key` in the
+    /// `low..=high` range in ascending `key` order.
+    ///
+    /// The levels are used to reach the first `Node` in the range, then the
+    /// bottom level is walked lazily until a `key` greater than `high` is
+    /// found
+    pub fn range_iter(&self, low: usize, high: usize) -> impl Iterator<Item = (usize, T)> + '_ {
+        SkipIter::new(self.first_not_lower(low)).take_while(move |(key, _)| *key <= high)
+    }
+}
+
+/// A `SkipList` is a `Collection` of its values, looking for a value walks
+/// the bottom level as `Node`s are sorted by `key` and not by value
+impl<T> Collection<T> for SkipList<T>
+where
+    T: std::fmt::Debug + PartialEq,
+{
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        let mut current = self.head[0].clone();
+
+        while let Some(node) = current {
+            if node.borrow().value == *value {
+                return true;
+            }
+
+            current = node.borrow().links[0].clone();
+        }
+
+        false
+    }
+}
+
+/// Creates an empty `SkipList` with up to `DEFAULT_MAX_LEVEL` levels where
+/// `Node`s are present on the next level half of the time
+impl<T> Default for SkipList<T>
+where
+    T: std::fmt::Debug,
+{
+    fn default() -> Self {
+        SkipList::new(DEFAULT_MAX_LEVEL)
+    }
+}
+
+impl<T> std::iter::FromIterator<(usize, T)> for SkipList<T>
+where
+    T: std::fmt::Debug,
+{
+    fn from_iter<I: IntoIterator<Item = (usize, T)>>(iter: I) -> Self {
+        let mut list = SkipList::default();
+
+        for (key, value) in iter {
+            list.insert(key, value);
+        }
+
+        list
+    }
+}
+
+/// An iterator over the `(key, value)` pairs of a `SkipList` which walks the
+/// bottom level, where every `Node` is present.
+///
+/// The iterator borrows the `SkipList`, so the list can't be modified while
+/// the iterator is alive
+pub struct SkipIter<'a, T>
+where
+    T: std::fmt::Debug,
+{
+    current: Link<T>,
+    list: PhantomData<&'a SkipList<T>>,
+}
+
+impl<'a, T> SkipIter<'a, T>
+where
+    T: std::fmt::Debug,
+{
+    fn new(current: Link<T>) -> Self {
+        SkipIter {
+            current,
+            list: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> Iterator for SkipIter<'a, T>
+where
+    T: std::fmt::Debug + Clone,
+{
+    type Item = (usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.current.take()?;
+        let node = node.borrow();
+
+        self.current = node.links[0].clone();
+
+        Some((node.key, node.value.clone()))
+    }
+}
+
+/// A cursor over the bottom level of a `SkipList` which starts before the
+/// first `Node` and moves one `Node` forward at a time, so sequential
+/// access doesn't search from the `head` on every step.
+///
+/// The cursor borrows the `SkipList`, so the list can't be modified while
+/// the cursor is alive
+pub struct SkipCursor<'a, T>
+where
+    T: std::fmt::Debug,
+{
+    current: Link<T>,
+    next: Link<T>,
+    list: PhantomData<&'a SkipList<T>>,
+}
+
+impl<'a, T> SkipCursor<'a, T>
+where
+    T: std::fmt::Debug,
+{
+    fn new(first: Link<T>) -> Self {
+        SkipCursor {
+            current: None,
+            next: first,
+            list: PhantomData,
+        }
+    }
+
+    /// Moves the cursor to the next `Node`, returning `false` once the
+    /// cursor moves past the last `Node`
+    pub fn move_next(&mut self) -> bool {
+        self.current = self.next.take();
+
+        match self.current {
+            Some(ref node) => {
+                self.next = node.borrow().links[0].clone();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Retrieves the `key` of the `Node` the cursor is placed on, if any
+    pub fn current_key(&self) -> Option<usize> {
+        self.current.as_ref().map(|node| node.borrow().key)
+    }
+}
+
+impl<'a, T> SkipCursor<'a, T>
+where
+    T: std::fmt::Debug + Clone,
+{
+    /// Retrieves a copy of the value of the `Node` the cursor is placed on,
+    /// if any
+    pub fn current_value(&self) -> Option<T> {
+        self.current
+            .as_ref()
+            .map(|node| node.borrow().value.clone())
+    }
+}
+
 mod tests {
     #[allow(unused_imports)]
     use super::*;
 
+    #[allow(dead_code)]
+    fn keys_on_level<T: std::fmt::Debug>(list: &SkipList<T>, level: usize) -> Vec<usize> {
+        let mut keys = Vec::new();
+        let mut current = list.head[level].clone();
+
+        while let Some(node) = current {
+            keys.push(node.borrow().key);
+            current = node.borrow().links[level].clone();
+        }
+
+        keys
+    }
+
     #[test]
     fn creates_an_empty_skip_list() {
         let list = SkipList::<String>::new(3);
 
-        assert!(list.head.is_none());
+        assert!(list.head.iter().all(Option::is_none));
+        assert_eq!(list.head.len(), 4);
         assert_eq!(list.tails.len(), 4);
         assert_eq!(list.length, 0);
         assert_eq!(list.max_level, 3);
@@ -124,9 +592,411 @@ mod tests {
 
         list.append(String::from("Hello World"));
 
-        assert!(list.head.is_some());
+        assert!(list.head[0].is_some());
         assert_eq!(list.tails.len(), 4);
         assert_eq!(list.length, 1);
         assert_eq!(list.max_level, 3);
     }
+
+    #[test]
+    fn appends_after_the_highest_key() {
+        let mut list = SkipList::<char>::with_seed(4, 0.5, 9);
+
+        list.append('a');
+        list.insert(10, 'b');
+        list.append('c');
+
+        assert_eq!(
+            list.iter().collect::<Vec<(usize, char)>>(),
+            vec![(0, 'a'), (10, 'b'), (11, 'c')]
+        );
+    }
+
+    #[test]
+    fn inserts_a_node_to_the_list() {
+        let mut list = SkipList::<String>::new(3);
+
+        list.insert(1, String::from("Hello World"));
+
+        assert!(list.head[0].is_some());
+        assert!(list.tails[0].is_some());
+        assert_eq!(list.tails.len(), 4);
+        assert_eq!(list.length, 1);
+        assert_eq!(list.max_level, 3);
+    }
+
+    #[test]
+    fn inserts_nodes_sorted_by_key() {
+        let mut list = SkipList::<String>::new(4);
+
+        for key in [5, 1, 9, 3, 7, 2, 8].iter() {
+            list.insert(*key, format!("Value {}", key));
+        }
+
+        let tail = list.tails[0].clone().unwrap();
+
+        assert_eq!(list.length, 7);
+        assert_eq!(keys_on_level(&list, 0), vec![1, 2, 3, 5, 7, 8, 9]);
+        assert_eq!(tail.borrow().peek_key(), 9);
+        assert_eq!(*tail.borrow().peek_value(), String::from("Value 9"));
+
+        // every level is a sorted subset of the level below
+        for level in 1..list.head.len() {
+            let keys = keys_on_level(&list, level);
+            let below = keys_on_level(&list, level - 1);
+
+            assert!(keys.windows(2).all(|pair| pair[0] <= pair[1]));
+            assert!(keys.iter().all(|key| below.contains(key)));
+        }
+    }
+
+    #[test]
+    fn finds_nodes_by_key() {
+        let mut list = SkipList::<String>::new(4);
+
+        for key in [5, 1, 9, 3, 7].iter() {
+            list.insert(*key, format!("Value {}", key));
+        }
+
+        for key in [1, 3, 5, 7, 9].iter() {
+            let node = list.find(*key).unwrap();
+
+            assert_eq!(node.borrow().peek_key(), *key);
+            assert_eq!(*node.borrow().peek_value(), format!("Value {}", key));
+        }
+
+        assert!(list.find(0).is_none());
+        assert!(list.find(4).is_none());
+        assert!(list.find(10).is_none());
+        assert!(SkipList::<String>::new(4).find(1).is_none());
+    }
+
+    #[test]
+    fn generates_reproducible_levels_from_a_seed() {
+        let list = SkipList::<String>::with_seed(8, 0.5, 42);
+        let other = SkipList::<String>::with_seed(8, 0.5, 42);
+        let levels: Vec<usize> = (0..100).map(|_| list.random_level()).collect();
+        let other_levels: Vec<usize> = (0..100).map(|_| other.random_level()).collect();
+
+        assert_eq!(levels, other_levels);
+        assert!(levels.iter().all(|level| *level <= 8));
+        assert!(levels.iter().any(|level| *level > 0));
+    }
+
+    #[test]
+    fn builds_the_same_structure_from_a_seed() {
+        let mut list = SkipList::<u64>::with_seed(4, 0.5, 7);
+        let mut other = SkipList::<u64>::with_seed(4, 0.5, 7);
+
+        for key in [5, 1, 9, 3, 7, 2, 8, 4, 6].iter() {
+            list.insert(*key, *key as u64);
+            other.insert(*key, *key as u64);
+        }
+
+        for level in 0..list.head.len() {
+            assert_eq!(keys_on_level(&list, level), keys_on_level(&other, level));
+        }
+    }
+
+    #[test]
+    fn generates_levels_according_to_probability() {
+        let never = SkipList::<String>::with_seed(5, 0.0, 1);
+        let always = SkipList::<String>::with_seed(5, 1.0, 1);
+
+        for _ in 0..10 {
+            assert_eq!(never.random_level(), 0);
+            assert_eq!(always.random_level(), 5);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_an_invalid_probability() {
+        SkipList::<String>::with_seed(5, 1.5, 1);
+    }
+
+    #[test]
+    fn gets_values_by_key() {
+        let mut list = SkipList::<String>::new(4);
+
+        list.insert(3, String::from("Three"));
+        list.insert(1, String::from("One"));
+        list.insert(2, String::from("Two"));
+
+        assert!(list.contains(1));
+        assert!(list.contains(3));
+        assert!(!list.contains(4));
+        assert_eq!(list.get(2), Some(String::from("Two")));
+        assert_eq!(list.get(3), Some(String::from("Three")));
+        assert_eq!(list.get(0), None);
+    }
+
+    #[test]
+    fn removes_nodes_by_key() {
+        let mut list = SkipList::<String>::with_seed(4, 0.5, 3);
+
+        for key in [5, 1, 9, 3, 7].iter() {
+            list.insert(*key, format!("Value {}", key));
+        }
+
+        assert_eq!(list.remove(5), Some(String::from("Value 5")));
+        assert_eq!(list.remove(1), Some(String::from("Value 1")));
+        assert_eq!(list.remove(4), None);
+        assert_eq!(list.remove(1), None);
+        assert_eq!(list.length, 3);
+        assert_eq!(keys_on_level(&list, 0), vec![3, 7, 9]);
+        assert!(!list.contains(5));
+
+        for level in 1..list.head.len() {
+            let keys = keys_on_level(&list, level);
+
+            assert!(!keys.contains(&5) && !keys.contains(&1));
+        }
+    }
+
+    #[test]
+    fn removes_every_node() {
+        let mut list = SkipList::<u64>::with_seed(4, 0.5, 11);
+
+        for key in 0..20 {
+            list.insert(key, key as u64);
+        }
+
+        assert_eq!(list.remove(19), Some(19));
+
+        let tail = list.find(18).unwrap();
+
+        assert_eq!(list.tails[0].clone().unwrap().borrow().peek_key(), 18);
+        assert_eq!(list.remove(18), None);
+        assert!(!list.contains(18));
+        assert_eq!(*tail.borrow().peek_value(), 18);
+
+        for key in 0..18 {
+            assert_eq!(list.remove(key), Some(key as u64));
+        }
+
+        assert_eq!(list.length, 0);
+        assert!(list.head.iter().all(Option::is_none));
+        assert!(list.tails.iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn iterates_pairs_sorted_by_key() {
+        let mut list = SkipList::<String>::new(4);
+
+        for key in [5, 1, 9, 3].iter() {
+            list.insert(*key, format!("Value {}", key));
+        }
+
+        let pairs: Vec<(usize, String)> = list.iter().collect();
+
+        assert_eq!(
+            pairs,
+            vec![
+                (1, String::from("Value 1")),
+                (3, String::from("Value 3")),
+                (5, String::from("Value 5")),
+                (9, String::from("Value 9")),
+            ]
+        );
+        assert_eq!(SkipList::<String>::new(4).iter().next(), None);
+    }
+
+    #[test]
+    fn retrieves_length_and_current_level() {
+        let mut list = SkipList::<u64>::with_seed(3, 0.5, 5);
+
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.current_level(), 0);
+
+        for key in 0..50 {
+            list.insert(key, key as u64);
+
+            assert!(list.current_level() <= 3);
+        }
+
+        assert!(!list.is_empty());
+        assert_eq!(list.len(), 50);
+        assert!(list.current_level() > 0);
+
+        for key in 0..50 {
+            list.remove(key);
+
+            assert!(list.current_level() <= 3);
+        }
+
+        assert!(list.is_empty());
+        assert_eq!(list.current_level(), 0);
+    }
+
+    #[test]
+    fn lowers_current_level_when_top_levels_are_emptied() {
+        let mut list = SkipList::<u64>::with_seed(3, 1.0, 5);
+
+        list.insert(1, 1);
+
+        assert_eq!(list.current_level(), 3);
+
+        list.remove(1);
+
+        assert_eq!(list.current_level(), 0);
+    }
+
+    #[test]
+    fn retrieves_values_in_a_key_range() {
+        let mut list = SkipList::<u64>::new(4);
+
+        for key in [10, 20, 30, 40, 50].iter() {
+            list.insert(*key, *key as u64 * 10);
+        }
+
+        assert_eq!(list.range(20, 40), vec![200, 300, 400]);
+        assert_eq!(list.range(15, 45), vec![200, 300, 400]);
+        assert_eq!(list.range(21, 29), Vec::<u64>::new());
+        assert_eq!(list.range(40, 20), Vec::<u64>::new());
+        assert_eq!(list.range(0, 100), vec![100, 200, 300, 400, 500]);
+    }
+
+    #[test]
+    fn upserts_a_value_into_the_list() {
+        let mut list = SkipList::<&str>::new(4);
+
+        assert_eq!(list.upsert(1, "one"), None);
+        assert_eq!(list.upsert(2, "two"), None);
+        assert_eq!(list.upsert(1, "uno"), Some("one"));
+        assert_eq!(list.len(), 2);
+        assert_eq!(
+            list.iter().collect::<Vec<(usize, &str)>>(),
+            vec![(1, "uno"), (2, "two")]
+        );
+    }
+
+    #[test]
+    fn collects_pairs_into_a_skip_list() {
+        let list: SkipList<char> = vec![(3, 'c'), (1, 'a'), (5, 'e'), (2, 'b'), (4, 'd')]
+            .into_iter()
+            .collect();
+
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.min_key(), Some(1));
+        assert_eq!(list.max_key(), Some(5));
+        assert_eq!(
+            list.iter().collect::<Vec<(usize, char)>>(),
+            vec![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd'), (5, 'e')]
+        );
+    }
+
+    #[test]
+    fn retrieves_no_keys_from_an_empty_list() {
+        let list = SkipList::<char>::new(3);
+
+        assert_eq!(list.min_key(), None);
+        assert_eq!(list.max_key(), None);
+    }
+
+    #[test]
+    fn creates_an_empty_skip_list_by_default() {
+        let list: SkipList<String> = Default::default();
+
+        assert!(list.is_empty());
+        assert_eq!(list.max_level, DEFAULT_MAX_LEVEL);
+        assert_eq!(list.probability, 0.5);
+    }
+
+    #[test]
+    fn counts_the_nodes_on_each_level() {
+        let mut list = SkipList::<usize>::with_seed(8, 0.5, 7);
+
+        assert_eq!(list.node_count_per_level(), vec![0; 9]);
+
+        for key in 0..1000 {
+            list.insert(key, key);
+        }
+
+        let counts = list.node_count_per_level();
+
+        assert_eq!(counts.len(), 9);
+        assert_eq!(counts[0], 1000);
+        assert!(counts.windows(2).all(|pair| pair[0] >= pair[1]));
+        assert!((400..600).contains(&counts[1]));
+        assert!((150..350).contains(&counts[2]));
+
+        for (level, count) in counts.iter().enumerate() {
+            assert_eq!(keys_on_level(&list, level).len(), *count);
+        }
+    }
+
+    #[test]
+    fn iterates_lazily_over_a_key_range() {
+        let mut list = SkipList::<u64>::new(4);
+
+        for key in [10, 20, 30, 40, 50].iter() {
+            list.insert(*key, *key as u64 * 10);
+        }
+
+        assert_eq!(
+            list.range_iter(15, 45).collect::<Vec<(usize, u64)>>(),
+            vec![(20, 200), (30, 300), (40, 400)]
+        );
+        assert_eq!(
+            list.range_iter(15, 45)
+                .map(|(_, value)| value)
+                .collect::<Vec<u64>>(),
+            list.range(15, 45)
+        );
+        assert_eq!(
+            list.range_iter(0, 100)
+                .take(2)
+                .collect::<Vec<(usize, u64)>>(),
+            vec![(10, 100), (20, 200)]
+        );
+        assert_eq!(list.range_iter(51, 100).count(), 0);
+    }
+
+    #[test]
+    fn retrieves_the_floor_and_ceil_entries() {
+        let mut list = SkipList::<char>::with_seed(4, 0.5, 3);
+
+        list.insert(10, 'a');
+        list.insert(20, 'b');
+        list.insert(30, 'c');
+
+        assert_eq!(list.floor(20), Some((20, 'b')));
+        assert_eq!(list.ceil(20), Some((20, 'b')));
+        assert_eq!(list.floor(25), Some((20, 'b')));
+        assert_eq!(list.ceil(25), Some((30, 'c')));
+        assert_eq!(list.floor(5), None);
+        assert_eq!(list.ceil(5), Some((10, 'a')));
+        assert_eq!(list.floor(35), Some((30, 'c')));
+        assert_eq!(list.ceil(35), None);
+        assert_eq!(list.floor(usize::MAX), Some((30, 'c')));
+    }
+
+    #[test]
+    fn steps_through_the_keys_with_a_cursor() {
+        let mut list = SkipList::<u64>::with_seed(4, 0.5, 5);
+
+        for key in [30, 10, 50, 20, 40] {
+            list.insert(key, key as u64 * 10);
+        }
+
+        let mut cursor = list.cursor();
+        let mut keys = Vec::new();
+
+        assert_eq!(cursor.current_key(), None);
+        assert_eq!(cursor.current_value(), None);
+
+        while cursor.move_next() {
+            let key = cursor.current_key().unwrap();
+
+            assert_eq!(cursor.current_value(), Some(key as u64 * 10));
+            keys.push(key);
+        }
+
+        assert_eq!(keys, vec![10, 20, 30, 40, 50]);
+        assert_eq!(cursor.current_key(), None);
+        assert!(!cursor.move_next());
+        assert!(!SkipList::<u64>::new(4).cursor().move_next());
+    }
 }
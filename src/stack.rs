@@ -0,0 +1,123 @@
+//! Stack
+//!
+//! A LIFO (Last In, First Out) collection built on top of a
+//! `SinglyLinkedList`, values are pushed to and popped from the `head` of
+//! the list so both operations run in constant time
+use std::fmt::Debug;
+
+use crate::singly_linked_list::SinglyLinkedList;
+use crate::traits::Collection;
+
+/// A LIFO collection of values of type `T`
+#[derive(Debug)]
+pub struct Stack<T: Debug> {
+    list: SinglyLinkedList<T>,
+}
+
+impl<T> Stack<T>
+where
+    T: Debug,
+{
+    /// Creates a new empty `Stack`
+    pub fn new() -> Self {
+        Stack {
+            list: SinglyLinkedList::new(),
+        }
+    }
+
+    /// Retrieves the count of values in the `Stack`
+    pub fn len(&self) -> usize {
+        self.list.len() as usize
+    }
+
+    /// Returns `true` if the `Stack` holds no values
+    pub fn is_empty(&self) -> bool {
+        self.list.len() == 0
+    }
+
+    /// Pushes a value on top of the `Stack`
+    pub fn push(&mut self, value: T) {
+        self.list.prepend(value);
+    }
+}
+
+impl<T> Stack<T>
+where
+    T: Debug + Clone,
+{
+    /// Removes the value on top of the `Stack` and returns it
+    pub fn pop(&mut self) -> Option<T> {
+        self.list.pop()
+    }
+
+    /// Retrieves a copy of the value on top of the `Stack` without
+    /// removing it
+    pub fn peek(&self) -> Option<T> {
+        self.list.front()
+    }
+}
+
+impl<T> Default for Stack<T>
+where
+    T: Debug,
+{
+    fn default() -> Self {
+        Stack::new()
+    }
+}
+
+impl<T> Collection<T> for Stack<T>
+where
+    T: Debug + PartialEq,
+{
+    fn len(&self) -> usize {
+        self.list.len() as usize
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        self.list.contains(value)
+    }
+}
+
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[test]
+    fn creates_an_empty_stack() {
+        let stack = Stack::<u32>::new();
+
+        assert!(stack.is_empty());
+        assert_eq!(stack.len(), 0);
+        assert_eq!(stack.peek(), None);
+    }
+
+    #[test]
+    fn pops_values_in_lifo_order() {
+        let mut stack = Stack::<u32>::new();
+
+        stack.push(1);
+        stack.push(2);
+        stack.push(3);
+
+        assert_eq!(stack.len(), 3);
+        assert_eq!(stack.peek(), Some(3));
+        assert_eq!(stack.pop(), Some(3));
+        assert_eq!(stack.pop(), Some(2));
+
+        stack.push(4);
+
+        assert_eq!(stack.peek(), Some(4));
+        assert_eq!(stack.pop(), Some(4));
+        assert_eq!(stack.pop(), Some(1));
+        assert_eq!(stack.pop(), None);
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn creates_an_empty_stack_by_default() {
+        let stack: Stack<u32> = Default::default();
+
+        assert!(stack.is_empty());
+    }
+}
@@ -0,0 +1,72 @@
+//! Traits
+//!
+//! Behavior shared by every data structure in the crate, so generic code is
+//! able to accept any of them.
+
+/// A collection of values of type `T`
+pub trait Collection<T> {
+    /// Retrieves the count of values in the collection
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the collection holds no values
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the collection holds a value equal to `value`
+    fn contains(&self, value: &T) -> bool;
+}
+
+mod tests {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(dead_code)]
+    fn assert_has<C: Collection<i32>>(collection: &C, values: &[i32], missing: i32) {
+        assert_eq!(collection.len(), values.len());
+        assert_eq!(collection.is_empty(), values.is_empty());
+        assert!(values.iter().all(|value| collection.contains(value)));
+        assert!(!collection.contains(&missing));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn implements_collection_for_every_data_structure() {
+        use crate::prelude::*;
+
+        let values = [3, 1, 2];
+
+        let mut binary_search_tree = BinarySearchTree::new();
+        let mut deque = Deque::new();
+        let mut doubly_linked_list = DoublyLinkedList::new();
+        let mut dynamic_array = DynamicArray::new();
+        let mut queue = Queue::new();
+        let mut red_black_tree = RedBlackTree::new();
+        let mut singly_linked_list = SinglyLinkedList::new();
+        let mut skip_list = SkipList::new(4);
+        let mut stack = Stack::new();
+
+        for (key, value) in values.iter().enumerate() {
+            binary_search_tree.add(*value);
+            deque.push_back(*value);
+            doubly_linked_list.append(*value);
+            dynamic_array.add(*value);
+            queue.enqueue(*value);
+            red_black_tree.insert(*value);
+            singly_linked_list.append(*value);
+            skip_list.insert(key, *value);
+            stack.push(*value);
+        }
+
+        assert_has(&BinarySearchTree::new(), &[], 1);
+        assert_has(&binary_search_tree, &values, 4);
+        assert_has(&deque, &values, 4);
+        assert_has(&doubly_linked_list, &values, 4);
+        assert_has(&dynamic_array, &values, 4);
+        assert_has(&queue, &values, 4);
+        assert_has(&red_black_tree, &values, 4);
+        assert_has(&singly_linked_list, &values, 4);
+        assert_has(&skip_list, &values, 4);
+        assert_has(&stack, &values, 4);
+    }
+}
@@ -0,0 +1,20 @@
+//! Uses the `DynamicArray` from a `no_std` crate, run with
+//! `cargo test --no-default-features --test no_std` to check the library
+//! builds without the `std` feature.
+#![no_std]
+
+use dsa_in_rust::dynamic_array::DynamicArray;
+
+#[test]
+fn uses_a_dynamic_array_without_std() {
+    let mut list = DynamicArray::<u32>::new();
+
+    list.add(1);
+    list.add(2);
+    list.add(3);
+
+    assert_eq!(list.len(), 3);
+    assert_eq!(list.item_at(0), Some(1));
+    assert_eq!(list.item_at(2), Some(3));
+    assert_eq!(list.item_at(3), None);
+}